@@ -1,35 +1,58 @@
 //! Common helpers that are shared between various examples.
 //!
-//! These are just variants of various XED enums that can be used with clap.
+//! These are mostly value parsers that let XED enums be used with clap.
 
-use clap::builder::{StringValueParser, TypedValueParser};
+// Not every example uses every helper in here.
+#![allow(dead_code)]
+
+use clap::builder::{PossibleValuesParser, StringValueParser, TypedValueParser};
 use clap::error::ErrorKind;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, clap::ValueEnum)]
-pub enum MachineMode {
-    Long64,
-    LongCompat32,
-    LongCompat16,
-    Legacy32,
-    Legacy16,
-    Real16,
-    Real32,
+/// A value parser that accepts the name of any variant in `variants`.
+///
+/// `name` gives the name that is used on the command line for each variant.
+pub fn variant_parser<T>(
+    variants: &'static [T],
+    name: fn(&T) -> String,
+) -> impl TypedValueParser<Value = T>
+where
+    T: Copy + Send + Sync + 'static,
+{
+    let names: Vec<&'static str> = variants
+        .iter()
+        .map(|variant| &*Box::leak(name(variant).into_boxed_str()))
+        .collect();
+
+    PossibleValuesParser::new(names).map(move |value| {
+        *variants
+            .iter()
+            .find(|variant| name(variant) == value)
+            .expect("value was not one of the possible values")
+    })
 }
 
-impl MachineMode {
-    pub fn into_xed(self) -> xed::MachineMode {
-        use xed::MachineMode::*;
+/// Parser for [`xed::MachineMode`], using names like `long-compat32`.
+pub fn machine_mode_parser() -> impl TypedValueParser<Value = xed::MachineMode> {
+    variant_parser(xed::MachineMode::VARIANTS, |mode| {
+        let mut name = String::new();
 
-        match self {
-            Self::Long64 => Long64,
-            Self::LongCompat32 => LongCompat32,
-            Self::LongCompat16 => LongCompat16,
-            Self::Legacy32 => Legacy32,
-            Self::Legacy16 => Legacy16,
-            Self::Real32 => Real32,
-            Self::Real16 => Real16,
+        for c in format!("{mode:?}").chars() {
+            if c.is_ascii_uppercase() && !name.is_empty() {
+                name.push('-');
+            }
+
+            name.push(c.to_ascii_lowercase());
         }
-    }
+
+        name
+    })
+}
+
+/// Parser for [`xed::Syntax`], using names like `intel`.
+pub fn syntax_parser() -> impl TypedValueParser<Value = xed::Syntax> {
+    variant_parser(xed::Syntax::VARIANTS, |syntax| {
+        format!("{syntax:?}").to_ascii_lowercase()
+    })
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -67,23 +90,6 @@ impl AddressWidth {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, clap::ValueEnum)]
-pub enum Syntax {
-    Xed,
-    Att,
-    Intel,
-}
-
-impl Syntax {
-    pub fn into_xed(self) -> xed::Syntax {
-        match self {
-            Self::Xed => xed::Syntax::XED,
-            Self::Att => xed::Syntax::ATT,
-            Self::Intel => xed::Syntax::INTEL,
-        }
-    }
-}
-
 #[derive(Clone, Copy)]
 pub struct HexValueParser;
 
@@ -0,0 +1,384 @@
+use xed_sys::*;
+
+use super::{DecodedInst, Extension, Flag, FlagSet, IClass, IsaSet, Register};
+
+/// The value of XED's `VEXVALID` operand for EVEX encoded instructions.
+const VEXVALID_EVEX: u32 = 2;
+
+/// The value of XED's `EVVSPACE` operand for the CCMP/CTEST encodings.
+const EVVSPACE_SCC: u32 = 2;
+
+/// The prefix used to encode an APX instruction.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ApxEncoding {
+    /// A legacy instruction with a two-byte `REX2` prefix (`0xD5`).
+    Rex2,
+
+    /// An instruction with an extended EVEX prefix, either promoted to the APX
+    /// EVEX space (e.g. NDD/NF forms) or an EVEX instruction addressing the
+    /// extended GPRs.
+    Evex,
+}
+
+/// The condition code of a conditional CMP or TEST instruction (`ccmpcc`,
+/// `ctestcc`), as encoded in `EVEX.SCC`.
+///
+/// These follow the order of the `jcc` condition codes, except that the
+/// parity conditions are replaced by always true (`T`) and always false
+/// (`F`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ConditionCode {
+    /// Overflow (`OF=1`).
+    O,
+    /// No overflow (`OF=0`).
+    NO,
+    /// Below (`CF=1`).
+    B,
+    /// Not below (`CF=0`).
+    NB,
+    /// Zero (`ZF=1`).
+    Z,
+    /// Not zero (`ZF=0`).
+    NZ,
+    /// Below or equal (`CF=1 or ZF=1`).
+    BE,
+    /// Not below or equal (`CF=0 and ZF=0`).
+    NBE,
+    /// Sign (`SF=1`).
+    S,
+    /// No sign (`SF=0`).
+    NS,
+    /// Always true.
+    T,
+    /// Always false.
+    F,
+    /// Less (`SF!=OF`).
+    L,
+    /// Not less (`SF=OF`).
+    NL,
+    /// Less or equal (`ZF=1 or SF!=OF`).
+    LE,
+    /// Not less or equal (`ZF=0 and SF=OF`).
+    NLE,
+}
+
+impl ConditionCode {
+    fn from_scc(scc: u32) -> Option<Self> {
+        Some(match scc {
+            0 => Self::O,
+            1 => Self::NO,
+            2 => Self::B,
+            3 => Self::NB,
+            4 => Self::Z,
+            5 => Self::NZ,
+            6 => Self::BE,
+            7 => Self::NBE,
+            8 => Self::S,
+            9 => Self::NS,
+            10 => Self::T,
+            11 => Self::F,
+            12 => Self::L,
+            13 => Self::NL,
+            14 => Self::LE,
+            15 => Self::NLE,
+            _ => return None,
+        })
+    }
+}
+
+/// The APX-specific details of an instruction.
+///
+/// Returned by [`DecodedInst::apx_info`].
+///
+/// # Example
+/// ```
+/// # use xed::*;
+/// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+/// // ccmpz eax, ecx, {dfv=cf}
+/// let bytes = [0x62, 0xf4, 0x0c, 0x04, 0x39, 0xc8];
+/// let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+///
+/// let apx = inst.apx_info().unwrap();
+/// assert_eq!(apx.encoding(), ApxEncoding::Evex);
+/// assert_eq!(apx.condition(), Some(ConditionCode::Z));
+/// assert_eq!(apx.dfv_reg(), Some(Register::DFV1));
+/// assert!(apx.default_flags().unwrap().contains(Flag::CF));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ApxInfo {
+    encoding: ApxEncoding,
+    egpr: bool,
+    nd: bool,
+    nf: bool,
+    zero_upper: bool,
+    condition: Option<ConditionCode>,
+    dfv: Option<Register>,
+    iclass: IClass,
+}
+
+impl ApxInfo {
+    pub(crate) fn new(inst: &DecodedInst) -> Option<Self> {
+        if !classify_apx(inst) {
+            return None;
+        }
+
+        let raw = inst.as_raw();
+
+        let encoding = if unsafe { xed3_operand_get_rex2(raw) } != 0 {
+            ApxEncoding::Rex2
+        } else if unsafe { xed3_operand_get_vexvalid(raw) } == VEXVALID_EVEX {
+            ApxEncoding::Evex
+        } else {
+            return None;
+        };
+
+        let condition = if unsafe { xed3_operand_get_evvspace(raw) } == EVVSPACE_SCC {
+            ConditionCode::from_scc(unsafe { xed3_operand_get_scc(raw) })
+        } else {
+            None
+        };
+
+        Some(Self {
+            encoding,
+            egpr: unsafe { xed3_operand_get_has_egpr(raw) } != 0,
+            nd: unsafe { xed3_operand_get_nd(raw) } != 0,
+            nf: unsafe { xed3_operand_get_nf(raw) } != 0,
+            zero_upper: inst.is_apx_zu(),
+            condition,
+            dfv: inst.dfv_reg(),
+            iclass: inst.iclass(),
+        })
+    }
+
+    /// The prefix used to encode the instruction.
+    pub fn encoding(&self) -> ApxEncoding {
+        self.encoding
+    }
+
+    /// Whether the instruction uses one of the extended GPRs `R16`–`R31`.
+    pub fn uses_egpr(&self) -> bool {
+        self.egpr
+    }
+
+    /// Whether the result is written to a new data destination (`EVEX.ND`)
+    /// instead of the first source operand.
+    ///
+    /// For `push2` and `pop2` this bit is always set and does not select a new
+    /// destination.
+    pub fn new_data_destination(&self) -> bool {
+        self.nd
+    }
+
+    /// Whether the instruction does not update the status flags (`EVEX.NF`,
+    /// `{nf}`).
+    pub fn no_flags(&self) -> bool {
+        self.nf
+    }
+
+    /// Whether the upper bits of the destination register are zeroed (see
+    /// [`DecodedInst::is_apx_zu`]).
+    pub fn is_zero_upper(&self) -> bool {
+        self.zero_upper
+    }
+
+    /// The condition code of a `ccmpcc` or `ctestcc` instruction.
+    pub fn condition(&self) -> Option<ConditionCode> {
+        self.condition
+    }
+
+    /// The default flags values pseudo-register (`DFV0`–`DFV15`) of a `ccmpcc`
+    /// or `ctestcc` instruction (see [`DecodedInst::dfv_reg`]).
+    pub fn dfv_reg(&self) -> Option<Register> {
+        self.dfv
+    }
+
+    /// The flags that are set when the condition of a `ccmpcc` or `ctestcc`
+    /// instruction is false. All other status flags are cleared.
+    ///
+    /// The default flags values are encoded in `EVEX.[OF,SF,ZF,CF]`, so `DFVn`
+    /// has `OF`, `SF`, `ZF`, and `CF` in bits 3 to 0 of `n`.
+    pub fn default_flags(&self) -> Option<FlagSet> {
+        let dfv = self.dfv?.into_raw() - Register::DFV0.into_raw();
+
+        Some(
+            [(8, Flag::OF), (4, Flag::SF), (2, Flag::ZF), (1, Flag::CF)]
+                .into_iter()
+                .filter(|&(bit, _)| dfv & bit != 0)
+                .map(|(_, flag)| flag)
+                .collect(),
+        )
+    }
+
+    /// Whether the instruction is `push2`, `push2p`, `pop2`, or `pop2p`.
+    pub fn is_push2_pop2(&self) -> bool {
+        matches!(
+            self.iclass,
+            IClass::PUSH2 | IClass::PUSH2P | IClass::POP2 | IClass::POP2P
+        )
+    }
+
+    /// Whether the instruction has a push-pop acceleration hint (PPX), which
+    /// marks a `push`/`pop` pair as matching (`pushp`, `popp`, `push2p`,
+    /// `pop2p`).
+    pub fn has_ppx_hint(&self) -> bool {
+        matches!(
+            self.iclass,
+            IClass::PUSHP | IClass::POPP | IClass::PUSH2P | IClass::POP2P
+        )
+    }
+}
+
+/// A port of `xed_classify_apx`, which is not exported by the bundled XED
+/// library.
+pub(crate) fn classify_apx(inst: &DecodedInst) -> bool {
+    let raw = inst.as_raw();
+
+    unsafe {
+        if xed3_operand_get_rex2(raw) != 0 || xed3_operand_get_has_egpr(raw) != 0 {
+            return true;
+        }
+
+        // APX EVEX bits that are set but ignored (e.g. by instructions
+        // without a GPR or memory operand). EVEX.X4 is stored inverted.
+        if xed3_operand_get_rexb4(raw) != 0
+            || (xed3_operand_get_rexx4(raw) != 0 && xed3_operand_get_ubit(raw) != 0)
+        {
+            return true;
+        }
+    }
+
+    matches!(
+        inst.isa_set(),
+        IsaSet::APX_F
+            | IsaSet::APX_F_ADX
+            | IsaSet::APX_F_AMX
+            | IsaSet::APX_F_BMI1
+            | IsaSet::APX_F_BMI2
+            | IsaSet::APX_F_CET
+            | IsaSet::APX_F_CMPCCXADD
+            | IsaSet::APX_F_ENQCMD
+            | IsaSet::APX_F_INVPCID
+            | IsaSet::APX_F_KEYLOCKER
+            | IsaSet::APX_F_KEYLOCKER_WIDE
+            | IsaSet::APX_F_KOPB
+            | IsaSet::APX_F_KOPD
+            | IsaSet::APX_F_KOPQ
+            | IsaSet::APX_F_KOPW
+            | IsaSet::APX_F_LZCNT
+            | IsaSet::APX_F_MOVBE
+            | IsaSet::APX_F_MOVDIR64B
+            | IsaSet::APX_F_MOVDIRI
+            | IsaSet::APX_F_RAO_INT
+            | IsaSet::APX_F_SHA
+            | IsaSet::APX_F_USER_MSR
+            | IsaSet::APX_F_VMX
+    )
+}
+
+/// A port of `xed_decoded_inst_is_apx_zu`, which is not exported by the
+/// bundled XED library.
+pub(crate) fn is_apx_zu(inst: &DecodedInst) -> bool {
+    inst.extension() == Extension::APXEVEX && unsafe { xed3_operand_get_nd(inst.as_raw()) } != 0
+}
+
+/// A port of `xed_decoded_inst_get_dfv_reg`, which is not exported by the
+/// bundled XED library.
+pub(crate) fn get_dfv_reg(inst: &DecodedInst) -> Option<Register> {
+    let first = Register::DFV0.into_raw();
+    let last = Register::DFV15.into_raw();
+
+    let template = inst.inst();
+
+    // The DFV operand is usually the last one.
+    (0..template.noperands())
+        .rev()
+        .filter_map(|index| inst.reg(template.operand(index)?.name()))
+        .find(|reg| (first..=last).contains(&reg.into_raw()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn apx_info(bytes: &[u8]) -> Option<ApxInfo> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        decode(bytes, DecodeOptions::new(state)).unwrap().apx_info()
+    }
+
+    #[test]
+    fn rex2() {
+        // push r16
+        let apx = apx_info(&[0xd5, 0x10, 0x50]).unwrap();
+        assert_eq!(apx.encoding(), ApxEncoding::Rex2);
+        assert!(apx.uses_egpr());
+        assert!(!apx.has_ppx_hint() && !apx.is_push2_pop2());
+
+        // pushp r16
+        assert!(apx_info(&[0xd5, 0x18, 0x50]).unwrap().has_ppx_hint());
+        // popp r16
+        assert!(apx_info(&[0xd5, 0x18, 0x58]).unwrap().has_ppx_hint());
+    }
+
+    #[test]
+    fn promoted_evex() {
+        // add eax, eax, ecx
+        let apx = apx_info(&[0x62, 0xf4, 0x7c, 0x18, 0x01, 0xc8]).unwrap();
+        assert_eq!(apx.encoding(), ApxEncoding::Evex);
+        assert!(apx.new_data_destination() && !apx.no_flags());
+        assert!(apx.is_zero_upper());
+        assert!(!apx.uses_egpr());
+        assert_eq!(apx.condition(), None);
+        assert_eq!(apx.default_flags(), None);
+
+        // {nf} add eax, eax, ecx
+        let apx = apx_info(&[0x62, 0xf4, 0x7c, 0x1c, 0x01, 0xc8]).unwrap();
+        assert!(apx.new_data_destination() && apx.no_flags());
+
+        // add r17d, r17d, ecx
+        assert!(apx_info(&[0x62, 0xe4, 0x7c, 0x18, 0x01, 0xc8])
+            .unwrap()
+            .uses_egpr());
+
+        // add eax, ecx
+        let apx = apx_info(&[0x62, 0xf4, 0x7c, 0x08, 0x01, 0xc8]).unwrap();
+        assert!(!apx.new_data_destination() && !apx.is_zero_upper());
+    }
+
+    #[test]
+    fn conditional_cmp() {
+        // ccmpz eax, ecx, {dfv=cf}
+        let apx = apx_info(&[0x62, 0xf4, 0x0c, 0x04, 0x39, 0xc8]).unwrap();
+        assert_eq!(apx.condition(), Some(ConditionCode::Z));
+        assert_eq!(apx.dfv_reg(), Some(Register::DFV1));
+        assert_eq!(
+            apx.default_flags().unwrap(),
+            FlagSet::from_flags(&[Flag::CF])
+        );
+    }
+
+    #[test]
+    fn push2_pop2() {
+        // push2 rbx, rax
+        let apx = apx_info(&[0x62, 0xf4, 0x64, 0x18, 0xff, 0xf0]).unwrap();
+        assert!(apx.is_push2_pop2() && !apx.has_ppx_hint());
+
+        // push2p rbx, rax
+        let apx = apx_info(&[0x62, 0xf4, 0xe4, 0x18, 0xff, 0xf0]).unwrap();
+        assert!(apx.is_push2_pop2() && apx.has_ppx_hint());
+
+        // pop2 rbx, rax
+        assert!(apx_info(&[0x62, 0xf4, 0x64, 0x18, 0x8f, 0xc0])
+            .unwrap()
+            .is_push2_pop2());
+    }
+
+    #[test]
+    fn not_apx() {
+        // add rax, rbx
+        assert_eq!(apx_info(&[0x48, 0x01, 0xd8]), None);
+        // vaddps zmm0, zmm1, zmm2
+        assert_eq!(apx_info(&[0x62, 0xf1, 0x74, 0x48, 0x58, 0xc2]), None);
+    }
+}
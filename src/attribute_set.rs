@@ -0,0 +1,257 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+
+use xed_sys::*;
+
+use super::Attribute;
+
+/// A set of [`Attribute`]s.
+///
+/// This is what XED uses to describe every attribute of an instruction at
+/// once (see [`Inst::attributes`] and [`DecodedInst::attributes`]).
+///
+/// [`Inst::attributes`]: crate::Inst::attributes
+/// [`DecodedInst::attributes`]: crate::DecodedInst::attributes
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct AttributeSet(xed_attributes_t);
+
+impl AttributeSet {
+    pub fn from_ref(raw: &xed_attributes_t) -> &Self {
+        // SAFETY: AttributeSet is #[repr(transparent)]
+        unsafe { std::mem::transmute(raw) }
+    }
+
+    pub fn from_raw(raw: xed_attributes_t) -> Self {
+        Self(raw)
+    }
+
+    pub fn into_raw(self) -> xed_attributes_t {
+        self.0
+    }
+
+    pub fn as_raw(&self) -> &xed_attributes_t {
+        &self.0
+    }
+
+    pub fn as_raw_mut(&mut self) -> &mut xed_attributes_t {
+        &mut self.0
+    }
+}
+
+impl AttributeSet {
+    /// Create an empty attribute set.
+    pub const fn new() -> Self {
+        Self::from_mask(0)
+    }
+
+    /// Create an attribute set containing exactly the provided attributes.
+    pub fn from_attributes(attrs: &[Attribute]) -> Self {
+        attrs.iter().copied().collect()
+    }
+
+    /// Create an attribute set from a mask where bit `n` corresponds to the
+    /// attribute with the raw value `n`.
+    pub const fn from_mask(mask: u128) -> Self {
+        Self(xed_attributes_t {
+            a1: mask as u64,
+            a2: (mask >> 64) as u64,
+        })
+    }
+
+    /// Get the attributes as a mask.
+    pub fn as_mask(&self) -> u128 {
+        (self.0.a2 as u128) << 64 | self.0.a1 as u128
+    }
+
+    /// Whether `attr` is present within this attribute set.
+    pub fn contains(&self, attr: Attribute) -> bool {
+        self.as_mask() & attribute_mask(attr) != 0
+    }
+
+    /// Add `attr` to this attribute set.
+    pub fn insert(&mut self, attr: Attribute) {
+        *self = Self::from_mask(self.as_mask() | attribute_mask(attr));
+    }
+
+    /// Remove `attr` from this attribute set.
+    pub fn remove(&mut self, attr: Attribute) {
+        *self = Self::from_mask(self.as_mask() & !attribute_mask(attr));
+    }
+
+    /// Whether this attribute set contains no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.as_mask() == 0
+    }
+
+    /// The number of attributes present in this attribute set.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Whether every attribute in this set is also in `other`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.as_mask() & !other.as_mask() == 0
+    }
+
+    /// The attributes that are in either this set or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() | other.as_mask())
+    }
+
+    /// The attributes that are in both this set and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() & other.as_mask())
+    }
+
+    /// The attributes that are in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() & !other.as_mask())
+    }
+
+    /// Iterate over the attributes present in this attribute set.
+    pub fn iter(&self) -> AttributeSetIter {
+        AttributeSetIter {
+            mask: self.as_mask(),
+        }
+    }
+}
+
+fn attribute_mask(attr: Attribute) -> u128 {
+    1 << attr.into_raw()
+}
+
+impl Default for AttributeSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for AttributeSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_mask() == other.as_mask()
+    }
+}
+
+impl Eq for AttributeSet {}
+
+impl Hash for AttributeSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_mask().hash(state)
+    }
+}
+
+impl FromIterator<Attribute> for AttributeSet {
+    fn from_iter<I: IntoIterator<Item = Attribute>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Attribute> for AttributeSet {
+    fn extend<I: IntoIterator<Item = Attribute>>(&mut self, iter: I) {
+        for attr in iter {
+            self.insert(attr);
+        }
+    }
+}
+
+impl IntoIterator for &AttributeSet {
+    type Item = Attribute;
+    type IntoIter = AttributeSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for AttributeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Serialized as a sequence of the [`Attribute`]s it contains.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<Attribute>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Iterator over the [`Attribute`]s within an [`AttributeSet`].
+#[derive(Clone)]
+pub struct AttributeSetIter {
+    mask: u128,
+}
+
+impl Iterator for AttributeSetIter {
+    type Item = Attribute;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.mask != 0 {
+            let index = self.mask.trailing_zeros();
+            self.mask &= !(1 << index);
+
+            if let Some(attr) = Attribute::from_raw(index) {
+                return Some(attr);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for AttributeSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    #[test]
+    fn set_algebra() {
+        let a = AttributeSet::from_attributes(&[Attribute::ATOMIC, Attribute::RING0]);
+        let b = AttributeSet::from_attributes(&[Attribute::RING0, Attribute::SCALABLE]);
+
+        assert!(a.contains(Attribute::ATOMIC));
+        assert!(!a.contains(Attribute::SCALABLE));
+        assert_eq!(a.len(), 2);
+        assert_eq!(
+            a.intersection(&b),
+            AttributeSet::from_attributes(&[Attribute::RING0])
+        );
+        assert_eq!(a.union(&b).len(), 3);
+        assert!(a.difference(&b).is_subset_of(&a));
+        assert_eq!(format!("{a:?}"), "{ATOMIC, RING0}");
+    }
+
+    #[test]
+    fn matches_individual_attributes() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // lock xadd [rax], ecx
+        let bytes = [0xf0, 0x0f, 0xc1, 0x08];
+        let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+        let attrs = inst.attributes();
+
+        assert!(attrs.contains(Attribute::LOCKED));
+        assert_eq!(attrs, inst.inst().attributes());
+
+        for raw in 0..XED_ATTRIBUTE_LAST {
+            if let Some(attr) = Attribute::from_raw(raw) {
+                assert_eq!(attrs.contains(attr), inst.attribute(attr), "{attr:?}");
+            }
+        }
+    }
+}
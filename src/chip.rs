@@ -1,5 +1,7 @@
 use xed_sys::*;
 
+use super::IsaSet;
+
 crate::macros::xed_enum! {
     pub enum Chip {
         I86,
@@ -76,3 +78,133 @@ crate::macros::xed_enum! {
         ALL,
     }
 }
+
+impl Chip {
+    /// Iterate over every ISA set supported by this chip.
+    pub fn isa_sets(self) -> impl Iterator<Item = IsaSet> {
+        IsaSet::iter().filter(move |isa_set| isa_set.is_valid_for_chip(self))
+    }
+
+    /// Iterate over every chip except the [`Chip::ALL`] and [`Chip::ALLREAL`]
+    /// pseudo-chips.
+    pub(crate) fn real_chips() -> impl Iterator<Item = Chip> {
+        Self::iter().filter(|&chip| chip != Self::ALL && chip != Self::ALLREAL)
+    }
+
+    /// The earliest chip in each product line for which `supported` is true.
+    ///
+    /// See [`PRODUCT_LINES`] for how the chips are grouped.
+    pub(crate) fn earliest_in_each_line(mut supported: impl FnMut(Chip) -> bool) -> Vec<Chip> {
+        PRODUCT_LINES
+            .iter()
+            .filter_map(|line| line.iter().copied().find(|&chip| supported(chip)))
+            .collect()
+    }
+}
+
+/// Every real chip, grouped into product lines ordered from oldest to newest.
+///
+/// XED's own chip order interleaves the lines (e.g. the Atom chips are listed
+/// between AMD and the Intel core chips), so it cannot be used to tell when a
+/// line gained a feature.
+const PRODUCT_LINES: &[&[Chip]] = &[
+    // Intel core and server
+    &[
+        Chip::I86,
+        Chip::I86FP,
+        Chip::I186,
+        Chip::I186FP,
+        Chip::I286REAL,
+        Chip::I286,
+        Chip::I2186FP,
+        Chip::I386REAL,
+        Chip::I386,
+        Chip::I386FP,
+        Chip::I486REAL,
+        Chip::I486,
+        Chip::PENTIUMREAL,
+        Chip::PENTIUM,
+        Chip::QUARK,
+        Chip::PENTIUMMMXREAL,
+        Chip::PENTIUMMMX,
+        Chip::PENTIUMPRO,
+        Chip::PENTIUM2,
+        Chip::PENTIUM3,
+        Chip::PENTIUM4,
+        Chip::P4PRESCOTT,
+        Chip::P4PRESCOTT_NOLAHF,
+        Chip::P4PRESCOTT_VTX,
+        Chip::MEROM,
+        Chip::PENRYN,
+        Chip::PENRYN_E,
+        Chip::NEHALEM,
+        Chip::WESTMERE,
+        Chip::SANDYBRIDGE,
+        Chip::IVYBRIDGE,
+        Chip::HASWELL,
+        Chip::BROADWELL,
+        Chip::SKYLAKE,
+        Chip::COMET_LAKE,
+        Chip::SKYLAKE_SERVER,
+        Chip::CASCADE_LAKE,
+        Chip::COOPER_LAKE,
+        Chip::CANNONLAKE,
+        Chip::ICE_LAKE,
+        Chip::ICE_LAKE_SERVER,
+        Chip::TIGER_LAKE,
+        Chip::ALDER_LAKE,
+        Chip::SAPPHIRE_RAPIDS,
+        Chip::EMERALD_RAPIDS,
+        Chip::GRANITE_RAPIDS,
+        Chip::ARROW_LAKE,
+        Chip::LUNAR_LAKE,
+        Chip::PANTHER_LAKE,
+        Chip::FUTURE,
+    ],
+    // Intel Atom
+    &[
+        Chip::BONNELL,
+        Chip::SALTWELL,
+        Chip::SILVERMONT,
+        Chip::GOLDMONT,
+        Chip::GOLDMONT_PLUS,
+        Chip::TREMONT,
+        Chip::SNOW_RIDGE,
+        Chip::LAKEFIELD,
+        Chip::SIERRA_FOREST,
+        Chip::CLEARWATER_FOREST,
+    ],
+    // Intel Xeon Phi
+    &[Chip::KNL, Chip::KNM],
+    // AMD
+    &[
+        Chip::AMD_K10,
+        Chip::AMD_BULLDOZER,
+        Chip::AMD_PILEDRIVER,
+        Chip::AMD_ZEN,
+        Chip::AMD_ZENPLUS,
+        Chip::AMD_ZEN2,
+        Chip::AMD_FUTURE,
+    ],
+    // VIA
+    &[Chip::VIA],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_lines_cover_every_chip_once() {
+        for chip in Chip::real_chips() {
+            let lines = PRODUCT_LINES
+                .iter()
+                .filter(|line| line.contains(&chip))
+                .count();
+            assert_eq!(lines, 1, "{chip:?}");
+        }
+
+        let listed: usize = PRODUCT_LINES.iter().map(|line| line.len()).sum();
+        assert_eq!(listed, Chip::real_chips().count());
+    }
+}
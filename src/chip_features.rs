@@ -1,7 +1,18 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+
 use xed_sys::*;
 
-use super::{Chip, IsaSet};
+use super::{Chip, IsaSet, UnsupportedHostError};
 
+/// A set of [`IsaSet`]s that are supported by a chip.
+///
+/// This can be passed to [`DecodeOptions::features`] to reject instructions
+/// that do not belong to one of the enabled ISA sets.
+///
+/// [`DecodeOptions::features`]: crate::DecodeOptions::features
+#[derive(Copy, Clone)]
 #[repr(transparent)]
 pub struct ChipFeatures(xed_chip_features_t);
 
@@ -29,21 +40,363 @@ impl ChipFeatures {
 }
 
 impl ChipFeatures {
+    /// Create an empty set of chip features.
+    pub const fn new() -> Self {
+        Self(xed_chip_features_t {
+            f: [0; XED_FEATURE_VECTOR_MAX as usize],
+        })
+    }
+
     /// Create this chip features with those corresponding to the provided
     /// [`Chip`].
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
     pub fn from_chip(chip: Chip) -> Self {
-        let mut features: xed_chip_features_t = unsafe { std::mem::zeroed() };
-        unsafe { xed_get_chip_features(&mut features, chip.into_raw()) };
-        Self(features)
+        chip.isa_sets().collect()
+    }
+
+    /// Compare the features of two chips.
+    ///
+    /// # Example
+    /// Find out what we lose by targeting Haswell instead of Skylake-SP.
+    /// ```
+    /// # use xed::*;
+    /// let diff = ChipFeatures::diff(Chip::SKYLAKE_SERVER, Chip::HASWELL);
+    ///
+    /// assert!(diff.removed().contains(IsaSet::AVX512F_512));
+    /// assert!(!diff.added().contains(IsaSet::AVX2));
+    /// ```
+    pub fn diff(from: Chip, to: Chip) -> ChipFeaturesDiff {
+        let from = Self::from_chip(from);
+        let to = Self::from_chip(to);
+
+        ChipFeaturesDiff {
+            added: to.difference(&from),
+            removed: from.difference(&to),
+        }
+    }
+
+    /// Detect the features of the CPU this program is running on.
+    ///
+    /// This runs `cpuid` (and `xgetbv`) to find every [`IsaSet`] whose
+    /// [CPUID requirements](IsaSet::cpuid_groups) are met and that the OS has
+    /// enabled. ISA sets that cannot be detected using CPUID, such as the
+    /// original 8086 instructions, are enabled if XED considers them part of
+    /// the newest chip from the host's vendor.
+    ///
+    /// Returns an error if the host is not an x86 machine.
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub fn from_host() -> Result<Self, UnsupportedHostError> {
+        super::init_tables();
+
+        let cpu = super::host::HostCpu::new();
+        let baseline = cpu.baseline();
+        let mut features = Self::new();
+
+        for isa_set in IsaSet::iter() {
+            let groups = isa_set.cpuid_groups();
+            let supported = match groups.is_empty() {
+                true => isa_set.is_valid_for_chip(baseline),
+                false => groups
+                    .iter()
+                    .any(|group| group.bits().into_iter().all(|bit| cpu.is_set(bit))),
+            };
+
+            if supported {
+                features.enable_isa_set(isa_set);
+            }
+        }
+
+        Ok(features)
+    }
+
+    /// Detect the features of the CPU this program is running on.
+    ///
+    /// Returns an error if the host is not an x86 machine.
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    pub fn from_host() -> Result<Self, UnsupportedHostError> {
+        Err(UnsupportedHostError::new())
     }
 
     /// Enable the features that correspond to the provided [`IsaSet`].
     pub fn enable_isa_set(&mut self, isa_set: IsaSet) {
-        unsafe { xed_modify_chip_features(self.as_raw_mut(), isa_set.into_raw(), 1) }
+        self.set(isa_set, true)
     }
 
     /// Disable the features that correspond to the provided [`IsaSet`].
     pub fn disable_isa_set(&mut self, isa_set: IsaSet) {
-        unsafe { xed_modify_chip_features(self.as_raw_mut(), isa_set.into_raw(), 0) }
+        self.set(isa_set, false)
+    }
+
+    /// Whether the provided [`IsaSet`] is enabled.
+    pub fn contains(&self, isa_set: IsaSet) -> bool {
+        let (word, mask) = Self::position(isa_set);
+        self.0.f[word] & mask != 0
+    }
+
+    /// Whether no ISA sets are enabled.
+    pub fn is_empty(&self) -> bool {
+        self.0.f.iter().all(|&word| word == 0)
+    }
+
+    /// The number of enabled ISA sets.
+    pub fn len(&self) -> usize {
+        self.0.f.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The ISA sets that are enabled in either this set or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// The ISA sets that are enabled in both this set and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// The ISA sets that are enabled in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    /// Whether every ISA set enabled in this set is also enabled in `other`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Iterate over the enabled ISA sets.
+    pub fn iter(&self) -> ChipFeaturesIter {
+        ChipFeaturesIter {
+            features: *self,
+            index: 0,
+        }
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let mut result = *self;
+
+        for (word, &other) in result.0.f.iter_mut().zip(other.0.f.iter()) {
+            *word = op(*word, other);
+        }
+
+        result
+    }
+
+    fn position(isa_set: IsaSet) -> (usize, u64) {
+        let index = isa_set.into_raw() as usize;
+        (index / 64, 1 << (index % 64))
+    }
+
+    // The static library built by xed-sys does not include
+    // xed_modify_chip_features so we need to do this ourselves.
+    fn set(&mut self, isa_set: IsaSet, present: bool) {
+        let (word, mask) = Self::position(isa_set);
+        let word = &mut self.0.f[word];
+
+        match present {
+            true => *word |= mask,
+            false => *word &= !mask,
+        }
+    }
+}
+
+impl Default for ChipFeatures {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for ChipFeatures {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.f == other.0.f
+    }
+}
+
+impl Eq for ChipFeatures {}
+
+impl Hash for ChipFeatures {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.f.hash(state)
+    }
+}
+
+impl FromIterator<IsaSet> for ChipFeatures {
+    fn from_iter<I: IntoIterator<Item = IsaSet>>(iter: I) -> Self {
+        let mut features = Self::new();
+        features.extend(iter);
+        features
+    }
+}
+
+impl Extend<IsaSet> for ChipFeatures {
+    fn extend<I: IntoIterator<Item = IsaSet>>(&mut self, iter: I) {
+        for isa_set in iter {
+            self.enable_isa_set(isa_set);
+        }
+    }
+}
+
+impl IntoIterator for &ChipFeatures {
+    type Item = IsaSet;
+    type IntoIter = ChipFeaturesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for ChipFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Serialized as a sequence of the [`IsaSet`]s it contains.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChipFeatures {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChipFeatures {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<IsaSet>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Iterator over the [`IsaSet`]s enabled within a [`ChipFeatures`].
+#[derive(Clone)]
+pub struct ChipFeaturesIter {
+    features: ChipFeatures,
+    index: u32,
+}
+
+impl Iterator for ChipFeaturesIter {
+    type Item = IsaSet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < XED_ISA_SET_LAST {
+            let isa_set = IsaSet::from_raw(self.index);
+            self.index += 1;
+
+            match isa_set {
+                Some(isa_set) if self.features.contains(isa_set) => return Some(isa_set),
+                _ => (),
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for ChipFeaturesIter {}
+
+/// The difference between the features of two chips, as returned by
+/// [`ChipFeatures::diff`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChipFeaturesDiff {
+    added: ChipFeatures,
+    removed: ChipFeatures,
+}
+
+impl ChipFeaturesDiff {
+    /// The ISA sets supported by the second chip but not the first.
+    pub fn added(&self) -> &ChipFeatures {
+        &self.added
+    }
+
+    /// The ISA sets supported by the first chip but not the second.
+    pub fn removed(&self) -> &ChipFeatures {
+        &self.removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    #[test]
+    fn from_chip() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // vmovdqu32 zmm0{k1}, zmm1
+        let bytes = [0x62, 0xf1, 0x7e, 0x49, 0x6f, 0xc1];
+
+        let features = ChipFeatures::from_chip(Chip::SKYLAKE_SERVER);
+        assert!(decode(&bytes, DecodeOptions::new(state).features(features)).is_ok());
+
+        let features = ChipFeatures::from_chip(Chip::HASWELL);
+        assert_eq!(
+            decode(&bytes, DecodeOptions::new(state).features(features)).err(),
+            Some(crate::Error::INVALID_FOR_CHIP)
+        );
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn from_host() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let features = ChipFeatures::from_host().unwrap();
+
+        // paddd xmm0, xmm1 - every x86-64 machine has SSE2.
+        let bytes = [0x66, 0x0f, 0xfe, 0xc1];
+        assert!(decode(&bytes, DecodeOptions::new(state).features(features)).is_ok());
+    }
+
+    #[test]
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    fn from_host() {
+        assert!(ChipFeatures::from_host().is_err());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = ChipFeatures::from_iter([IsaSet::I86, IsaSet::SSE, IsaSet::AVX]);
+        let b = ChipFeatures::from_iter([IsaSet::SSE, IsaSet::AVX2]);
+
+        assert!(a.contains(IsaSet::AVX));
+        assert!(!a.contains(IsaSet::AVX2));
+        assert_eq!(a.len(), 3);
+        assert!(ChipFeatures::new().is_empty());
+        assert_eq!(
+            a.union(&b),
+            ChipFeatures::from_iter([IsaSet::I86, IsaSet::SSE, IsaSet::AVX, IsaSet::AVX2])
+        );
+        assert_eq!(a.intersection(&b), ChipFeatures::from_iter([IsaSet::SSE]));
+        assert_eq!(
+            a.difference(&b),
+            ChipFeatures::from_iter([IsaSet::I86, IsaSet::AVX])
+        );
+        assert!(a.intersection(&b).is_subset_of(&a));
+
+        let mut c = a;
+        c.disable_isa_set(IsaSet::I86);
+        assert_ne!(a, c);
+        assert_eq!(c.iter().collect::<Vec<_>>(), [IsaSet::AVX, IsaSet::SSE]);
+        assert_eq!(format!("{c:?}"), "{AVX, SSE}");
+    }
+
+    #[test]
+    fn chip_contents() {
+        let haswell = ChipFeatures::from_chip(Chip::HASWELL);
+
+        assert!(haswell.contains(IsaSet::AVX2));
+        assert!(!haswell.contains(IsaSet::AVX512F_512));
+        assert!(haswell.iter().eq(Chip::HASWELL.isa_sets()));
+        assert!(haswell.is_subset_of(&ChipFeatures::from_chip(Chip::SKYLAKE)));
+
+        let diff = ChipFeatures::diff(Chip::SKYLAKE, Chip::ICE_LAKE);
+        assert!(diff.added().contains(IsaSet::AVX512F_512));
+        assert_eq!(*diff.removed(), ChipFeatures::from_iter([IsaSet::MPX]));
     }
 }
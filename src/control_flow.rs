@@ -0,0 +1,312 @@
+use super::{Category, DecodedInst, IClass, Operand, OperandVisibility, Register};
+
+/// How an instruction affects the flow of execution.
+///
+/// Returned by [`DecodedInst::control_flow`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ControlFlow {
+    /// Execution always continues with the next instruction.
+    Fallthrough,
+
+    /// An unconditional jump to a known target.
+    DirectJump(BranchTarget),
+
+    /// A jump that is taken or not depending on some condition (`jcc`, `loop`,
+    /// `jrcxz`, `xbegin`).
+    ConditionalJump {
+        /// The address jumped to when the branch is taken.
+        target: u64,
+
+        /// The address of the next instruction, which is executed when the
+        /// branch is not taken.
+        fallthrough: u64,
+    },
+
+    /// A call to a known target.
+    DirectCall(BranchTarget),
+
+    /// A jump whose target is read from a register or from memory.
+    IndirectJump(IndirectTarget),
+
+    /// A call whose target is read from a register or from memory.
+    IndirectCall(IndirectTarget),
+
+    /// A return from a procedure, interrupt handler, or system call.
+    Return {
+        /// The number of additional bytes popped from the stack (the `imm16`
+        /// of `ret imm16`).
+        pop: u16,
+    },
+
+    /// A software interrupt or system call (`int`, `int3`, `syscall`,
+    /// `sysenter`, ...).
+    Interrupt,
+
+    /// Execution stops or faults (`hlt`, `ud0`, `ud1`, `ud2`).
+    Halt,
+}
+
+/// The target of a [`ControlFlow::DirectJump`] or [`ControlFlow::DirectCall`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BranchTarget {
+    /// A target within the current code segment.
+    Near(u64),
+
+    /// A target within another code segment, given by a far pointer
+    /// (`jmp ptr16:32`).
+    Far {
+        /// The code segment selector.
+        selector: u16,
+
+        /// The offset within the code segment.
+        offset: u64,
+    },
+}
+
+/// The location of the target of a [`ControlFlow::IndirectJump`] or
+/// [`ControlFlow::IndirectCall`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IndirectTarget {
+    /// The target is the value of a register.
+    Register(Register),
+
+    /// The target is read from memory.
+    Memory {
+        /// The index of the operand within [`DecodedInst::memory_operands`].
+        operand: usize,
+
+        /// Whether memory holds a far pointer (`jmp far ptr [rax]`) instead of
+        /// just an offset.
+        far: bool,
+    },
+}
+
+impl ControlFlow {
+    pub(crate) fn classify(inst: &DecodedInst, address: u64) -> Self {
+        let next = address.wrapping_add(inst.length() as u64);
+
+        match inst.category() {
+            Category::UNCOND_BR => match branch_target(inst, next) {
+                Some(target) => Self::DirectJump(target),
+                None => indirect_target(inst).map_or(Self::Fallthrough, Self::IndirectJump),
+            },
+            Category::CALL => match branch_target(inst, next) {
+                Some(target) => Self::DirectCall(target),
+                None => indirect_target(inst).map_or(Self::Fallthrough, Self::IndirectCall),
+            },
+            Category::COND_BR => match branch_target(inst, next) {
+                Some(BranchTarget::Near(target)) => Self::ConditionalJump {
+                    target,
+                    fallthrough: next,
+                },
+                _ => Self::Fallthrough,
+            },
+            Category::RET | Category::SYSRET => Self::Return {
+                pop: if has_operand(inst, Operand::IMM0) {
+                    inst.unsigned_immediate() as u16
+                } else {
+                    0
+                },
+            },
+            Category::INTERRUPT | Category::SYSCALL => Self::Interrupt,
+            _ => match inst.iclass() {
+                IClass::HLT | IClass::UD0 | IClass::UD1 | IClass::UD2 => Self::Halt,
+                _ => Self::Fallthrough,
+            },
+        }
+    }
+
+    /// Whether execution can continue with the next instruction.
+    ///
+    /// Calls and interrupts are assumed to return.
+    pub fn may_fall_through(&self) -> bool {
+        !matches!(
+            self,
+            Self::DirectJump(_) | Self::IndirectJump(_) | Self::Return { .. } | Self::Halt
+        )
+    }
+}
+
+/// The target of a relative branch, far pointer, or absolute jump.
+fn branch_target(inst: &DecodedInst, next: u64) -> Option<BranchTarget> {
+    if has_operand(inst, Operand::RELBR) {
+        let target = next.wrapping_add(inst.branch_displacement() as u64);
+
+        // Outside of 64-bit mode the instruction pointer is truncated to the
+        // operand size (e.g. `jmp rel16` with a 66 prefix in 32-bit mode).
+        let target = match inst.machine_mode_bits() {
+            64 => target,
+            _ => target & (u64::MAX >> (64 - inst.operand_width())),
+        };
+
+        Some(BranchTarget::Near(target))
+    } else if has_operand(inst, Operand::PTR) {
+        // XED stores the offset of a far pointer as the branch displacement
+        // and the segment selector as the immediate. The displacement is
+        // sign-extended, but the offset is unsigned.
+        let width = inst.branch_displacement_width_bits();
+
+        Some(BranchTarget::Far {
+            selector: inst.unsigned_immediate() as u16,
+            offset: inst.branch_displacement() as u64 & (u64::MAX >> (64 - width)),
+        })
+    } else if has_operand(inst, Operand::IMM0) {
+        // jmpabs imm64
+        Some(BranchTarget::Near(inst.unsigned_immediate()))
+    } else {
+        None
+    }
+}
+
+/// The first explicit register or memory operand of an indirect branch.
+fn indirect_target(inst: &DecodedInst) -> Option<IndirectTarget> {
+    let far = matches!(inst.iclass(), IClass::JMP_FAR | IClass::CALL_FAR);
+
+    inst.inst()
+        .operands()
+        .filter(|op| op.visibility() == OperandVisibility::EXPLICIT)
+        .find_map(|op| match op.name() {
+            Operand::MEM0 => Some(IndirectTarget::Memory { operand: 0, far }),
+            Operand::MEM1 => Some(IndirectTarget::Memory { operand: 1, far }),
+            name => inst.reg(name).map(IndirectTarget::Register),
+        })
+}
+
+fn has_operand(inst: &DecodedInst, name: Operand) -> bool {
+    inst.inst().operands().any(|op| op.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn control_flow(mode: MachineMode, bytes: &[u8], address: u64) -> ControlFlow {
+        let width = match mode {
+            MachineMode::Long64 => AddressWidth::QWord,
+            _ => AddressWidth::DWord,
+        };
+        let state = State::new(mode, width);
+
+        decode(bytes, DecodeOptions::new(state))
+            .unwrap()
+            .control_flow(address)
+    }
+
+    fn long64(bytes: &[u8]) -> ControlFlow {
+        control_flow(MachineMode::Long64, bytes, 0x1000)
+    }
+
+    #[test]
+    fn direct_branches() {
+        // jmp rel32
+        assert_eq!(
+            long64(&[0xe9, 0x10, 0x00, 0x00, 0x00]),
+            ControlFlow::DirectJump(BranchTarget::Near(0x1015))
+        );
+        // call rel32
+        assert_eq!(
+            long64(&[0xe8, 0xfb, 0xff, 0xff, 0xff]),
+            ControlFlow::DirectCall(BranchTarget::Near(0x1000))
+        );
+        // jnz rel8
+        assert_eq!(
+            long64(&[0x75, 0xfe]),
+            ControlFlow::ConditionalJump {
+                target: 0x1000,
+                fallthrough: 0x1002,
+            }
+        );
+        // loop rel8
+        assert!(matches!(
+            long64(&[0xe2, 0x00]),
+            ControlFlow::ConditionalJump { .. }
+        ));
+    }
+
+    #[test]
+    fn truncated_and_far_targets() {
+        // jmp rel16 in 32-bit mode wraps around at 64K
+        assert_eq!(
+            control_flow(MachineMode::Legacy32, &[0x66, 0xe9, 0x10, 0x00], 0xfff0),
+            ControlFlow::DirectJump(BranchTarget::Near(0x0004))
+        );
+        // jmp far 0x1234:0x12345678
+        assert_eq!(
+            control_flow(
+                MachineMode::Legacy32,
+                &[0xea, 0x78, 0x56, 0x34, 0x12, 0x34, 0x12],
+                0
+            ),
+            ControlFlow::DirectJump(BranchTarget::Far {
+                selector: 0x1234,
+                offset: 0x12345678,
+            })
+        );
+        // jmp far 0x8:0x80000000
+        assert_eq!(
+            control_flow(
+                MachineMode::Legacy32,
+                &[0xea, 0x00, 0x00, 0x00, 0x80, 0x08, 0x00],
+                0
+            ),
+            ControlFlow::DirectJump(BranchTarget::Far {
+                selector: 0x8,
+                offset: 0x80000000,
+            })
+        );
+        // jmp far 0xf000:0x9000
+        for mode in [MachineMode::Legacy16, MachineMode::Real16] {
+            assert_eq!(
+                control_flow(mode, &[0xea, 0x00, 0x90, 0x00, 0xf0], 0),
+                ControlFlow::DirectJump(BranchTarget::Far {
+                    selector: 0xf000,
+                    offset: 0x9000,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn indirect_branches() {
+        // jmp rax
+        assert_eq!(
+            long64(&[0xff, 0xe0]),
+            ControlFlow::IndirectJump(IndirectTarget::Register(Register::RAX))
+        );
+        // call qword ptr [rip+0x10]
+        assert_eq!(
+            long64(&[0xff, 0x15, 0x10, 0x00, 0x00, 0x00]),
+            ControlFlow::IndirectCall(IndirectTarget::Memory {
+                operand: 0,
+                far: false,
+            })
+        );
+        // jmp far ptr [rsp]
+        assert_eq!(
+            long64(&[0xff, 0x2c, 0x24]),
+            ControlFlow::IndirectJump(IndirectTarget::Memory {
+                operand: 0,
+                far: true,
+            })
+        );
+    }
+
+    #[test]
+    fn other_control_flow() {
+        assert_eq!(long64(&[0xc3]), ControlFlow::Return { pop: 0 });
+        assert_eq!(
+            long64(&[0xc2, 0x10, 0x00]),
+            ControlFlow::Return { pop: 0x10 }
+        );
+        assert_eq!(long64(&[0x48, 0xcf]), ControlFlow::Return { pop: 0 });
+        assert_eq!(long64(&[0x0f, 0x05]), ControlFlow::Interrupt);
+        assert_eq!(long64(&[0xcc]), ControlFlow::Interrupt);
+        assert_eq!(long64(&[0xf4]), ControlFlow::Halt);
+        assert_eq!(long64(&[0x0f, 0x0b]), ControlFlow::Halt);
+        assert_eq!(long64(&[0x48, 0x01, 0xd8]), ControlFlow::Fallthrough);
+
+        assert!(!long64(&[0xc3]).may_fall_through());
+        assert!(long64(&[0xe8, 0, 0, 0, 0]).may_fall_through());
+    }
+}
@@ -0,0 +1,257 @@
+use std::ops::RangeInclusive;
+
+use xed_sys::*;
+
+use super::{IsaSet, Register};
+
+crate::macros::xed_enum! {
+    /// A single CPUID feature bit (or bit field) that XED knows about.
+    ///
+    /// Use [`leaf`](Self::leaf), [`subleaf`](Self::subleaf),
+    /// [`register`](Self::register) and [`bits`](Self::bits) to find out where
+    /// the feature is reported by the `cpuid` instruction.
+    pub enum CpuidBit => XED_CPUID_REC {
+        ADOXADCX,
+        AES,
+        AMX_BF16,
+        AMX_COMPLEX,
+        AMX_FP16,
+        AMX_INT8,
+        AMX_TILES,
+        APX_F,
+        AVX,
+        AVX10_128VL,
+        AVX10_256VL,
+        AVX10_512VL,
+        AVX10_ENABLED,
+        AVX10_VER1,
+        AVX2,
+        AVX512BW,
+        AVX512CD,
+        AVX512DQ,
+        AVX512ER,
+        AVX512F,
+        AVX512IFMA,
+        AVX512PF,
+        AVX512VBMI,
+        AVX512VL,
+        AVX512_4FMAPS,
+        AVX512_4VNNIW,
+        AVX512_BITALG,
+        AVX512_FP16,
+        AVX512_VBMI2,
+        AVX512_VNNI,
+        AVX512_VP2INTERSECT,
+        AVX512_VPOPCNTDQ,
+        AVX_IFMA,
+        AVX_NE_CONVERT,
+        AVX_VNNI,
+        AVX_VNNI_INT16,
+        AVX_VNNI_INT8,
+        BF16,
+        BMI1,
+        BMI2,
+        CET,
+        CLDEMOTE,
+        CLFLUSH,
+        CLFLUSHOPT,
+        CLWB,
+        CMOV,
+        CMPCCXADD,
+        CMPXCHG16B,
+        ENQCMD,
+        F16C,
+        FMA,
+        FPU,
+        FRED,
+        FXSAVE,
+        GFNI,
+        HRESET,
+        ICACHE_PREFETCH,
+        INTEL64,
+        INTELPT,
+        INVPCID,
+        KLENABLED,
+        KLSUPPORTED,
+        KLWIDE,
+        LAHF,
+        LKGS,
+        LZCNT,
+        MCOMMIT,
+        MMX,
+        MONITOR,
+        MONITORX,
+        MOVDIR64B,
+        MOVDIRI,
+        MOVEBE,
+        MPX,
+        MSRLIST,
+        OSPKU,
+        OSXSAVE,
+        PBNDKB,
+        PCLMULQDQ,
+        PCONFIG,
+        PKU,
+        POPCNT,
+        PREFETCHW,
+        PREFETCHWT1,
+        PTWRITE,
+        RAO_INT,
+        RDP,
+        RDPRU,
+        RDRAND,
+        RDSEED,
+        RDTSCP,
+        RDWRFSGS,
+        RTM,
+        SERIALIZE,
+        SGX,
+        SHA,
+        SHA512,
+        SM3,
+        SM4,
+        SMAP,
+        SMX,
+        SNP,
+        SSE,
+        SSE2,
+        SSE3,
+        SSE4,
+        SSE42,
+        SSE4A,
+        SSSE3,
+        TSX_LDTRK,
+        UINTR,
+        USER_MSR,
+        VAES,
+        VIA_PADLOCK_AES,
+        VIA_PADLOCK_AES_EN,
+        VIA_PADLOCK_PMM,
+        VIA_PADLOCK_PMM_EN,
+        VIA_PADLOCK_RNG,
+        VIA_PADLOCK_RNG_EN,
+        VIA_PADLOCK_SHA,
+        VIA_PADLOCK_SHA_EN,
+        VMX,
+        VPCLMULQDQ,
+        WAITPKG,
+        WBNOINVD,
+        WRMSRNS,
+        XSAVE,
+        XSAVEC,
+        XSAVEOPT,
+        XSAVES,
+    }
+}
+
+impl CpuidBit {
+    fn record(self) -> xed_cpuid_rec_t {
+        let mut record: xed_cpuid_rec_t = unsafe { std::mem::zeroed() };
+        let found = unsafe { xed_get_cpuid_rec(self.into_raw(), &mut record) };
+        assert!(found != 0, "XED has no CPUID record for {self:?}");
+        record
+    }
+
+    /// The CPUID leaf (the value of EAX) that reports this feature.
+    pub fn leaf(self) -> u32 {
+        self.record().leaf
+    }
+
+    /// The CPUID subleaf (the value of ECX) that reports this feature.
+    pub fn subleaf(self) -> u32 {
+        self.record().subleaf
+    }
+
+    /// The register, one of EAX, EBX, ECX or EDX, that reports this feature.
+    pub fn register(self) -> Register {
+        self.record()
+            .reg
+            .try_into()
+            .expect("CPUID record had an invalid register")
+    }
+
+    /// The range of bits within [`register`](Self::register) that report this
+    /// feature.
+    ///
+    /// Most features are a single bit but some, such as the AVX10 version, are
+    /// a multi-bit field.
+    pub fn bits(self) -> RangeInclusive<u32> {
+        let record = self.record();
+        record.bit_start.into()..=record.bit_end.into()
+    }
+
+    /// The value that the [`bits`](Self::bits) field must have for the
+    /// feature to be present.
+    ///
+    /// For multi-bit fields, such as a version number, any larger value also
+    /// indicates that the feature is present.
+    pub fn value(self) -> u32 {
+        self.record().value
+    }
+
+    /// Whether this feature is present given the value of
+    /// [`register`](Self::register) returned by `cpuid` for
+    /// [`leaf`](Self::leaf) and [`subleaf`](Self::subleaf).
+    pub fn is_set(self, register: u32) -> bool {
+        let record = self.record();
+        let start = u32::from(record.bit_start);
+        let width = u32::from(record.bit_end) - start + 1;
+        let mask = u32::MAX >> (32 - width);
+
+        (register >> start) & mask >= record.value
+    }
+
+    /// Iterate over every ISA set that has this bit among its
+    /// [CPUID requirements](IsaSet::cpuid_groups).
+    pub fn isa_sets(self) -> impl Iterator<Item = IsaSet> {
+        IsaSet::iter().filter(move |isa_set| {
+            isa_set
+                .cpuid_groups()
+                .iter()
+                .any(|group| group.bits().contains(&self))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records() {
+        for bit in CpuidBit::iter() {
+            // Panics if XED has no record for the bit.
+            bit.register();
+            assert!(bit.bits().start() <= bit.bits().end());
+        }
+
+        assert_eq!(CpuidBit::AVX2.leaf(), 7);
+        assert_eq!(CpuidBit::AVX2.subleaf(), 0);
+        assert_eq!(CpuidBit::AVX2.register(), Register::EBX);
+        assert_eq!(CpuidBit::AVX2.bits(), 5..=5);
+
+        assert_eq!(CpuidBit::SSE3.leaf(), 1);
+        assert_eq!(CpuidBit::SSE3.register(), Register::ECX);
+        assert_eq!(CpuidBit::SSE3.bits(), 0..=0);
+    }
+
+    #[test]
+    fn is_set() {
+        assert!(CpuidBit::AVX2.is_set(1 << 5));
+        assert!(!CpuidBit::AVX2.is_set(!(1 << 5)));
+
+        assert_eq!(CpuidBit::AVX10_VER1.bits(), 0..=7);
+        assert!(CpuidBit::AVX10_VER1.is_set(0x01));
+        assert!(CpuidBit::AVX10_VER1.is_set(0x02));
+        assert!(!CpuidBit::AVX10_VER1.is_set(0x100));
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        let isa_sets: Vec<_> = CpuidBit::AVX512F.isa_sets().collect();
+
+        assert!(isa_sets.contains(&IsaSet::AVX512F_512));
+        assert!(isa_sets.contains(&IsaSet::AVX512F_128));
+        assert!(!isa_sets.contains(&IsaSet::AVX2));
+    }
+}
@@ -1,11 +1,17 @@
-use std::borrow::Cow;
+use std::ffi::c_void;
+use std::fmt;
 use std::marker::PhantomData;
 
 use xed_sys::*;
 
+use crate::apx_info::{classify_apx, get_dfv_reg, is_apx_zu};
+use crate::format::new_print_info;
+use crate::symbol::{inline_symbols, symbol_callback, RecordingResolver};
+use crate::token::tokenize;
 use crate::{
-    Attribute, Category, Chip, Extension, IClass, IForm, IsaSet, Operand, OperandAction,
-    OperandElementType, Register, SimpleFlag, Syntax,
+    ApxInfo, Attribute, AttributeSet, Category, Chip, ControlFlow, DisassembleError, EvexInfo,
+    Extension, FormatOptions, IClass, IForm, Inst, IsaSet, MemoryAccess, Operand, OperandAction,
+    OperandElementType, Register, SimpleFlag, StackEffect, SymbolResolver, Syntax, Token,
 };
 
 /// The main container for instructions.
@@ -66,9 +72,16 @@ impl<'d> DecodedInst<'d> {
             .expect("isa_set value was invalid")
     }
 
+    /// Get the instruction template that this instruction was decoded as.
+    pub fn inst(&self) -> &'static Inst {
+        unsafe { Inst::from_ref(&*xed_decoded_inst_inst(self.as_raw())) }
+    }
+
     /// Get the instruction class of this instruction.
     pub fn iclass(&self) -> IClass {
-        unsafe { xed_decoded_inst_get_iclass(self.as_raw()) }.into()
+        unsafe { xed_decoded_inst_get_iclass(self.as_raw()) }
+            .try_into()
+            .expect("iclass value was invalid")
     }
 }
 
@@ -79,9 +92,9 @@ impl<'d> DecodedInst<'d> {
         unsafe { xed_decoded_inst_get_attribute(self.as_raw(), attr.into()) != 0 }
     }
 
-    /// Get the whole attribute bitvector.
-    pub fn attributes(&self) -> xed_attributes_t {
-        unsafe { xed_decoded_inst_get_attributes(self.as_raw()) }
+    /// Get every attribute of this instruction.
+    pub fn attributes(&self) -> AttributeSet {
+        AttributeSet::from_raw(unsafe { xed_decoded_inst_get_attributes(self.as_raw()) })
     }
 
     /// Whether this instruction is xacquire.
@@ -96,7 +109,13 @@ impl<'d> DecodedInst<'d> {
 
     /// Whether this instruction ia an APX-promoted zero-upper (ZU) instruction.
     pub fn is_apx_zu(&self) -> bool {
-        unsafe { xed_decoded_inst_is_apx_zu(self.as_raw()) != 0 }
+        is_apx_zu(self)
+    }
+
+    /// The APX-specific details of the instruction, or `None` if it is not an
+    /// APX instruction (see [`classify_apx`](Self::classify_apx)).
+    pub fn apx_info(&self) -> Option<ApxInfo> {
+        ApxInfo::new(self)
     }
 
     /// Get the modrm byte.
@@ -134,7 +153,7 @@ impl<'d> DecodedInst<'d> {
 
 /// Operands
 impl<'d> DecodedInst<'d> {
-    pub fn operands(&self) -> OperandValues {
+    pub fn operands(&self) -> OperandValues<'_, 'd> {
         OperandValues::new(self)
     }
 }
@@ -156,7 +175,7 @@ impl<'a, 'd> OperandValues<'a, 'd> {
         unsafe { xed_decoded_inst_noperands(self.inst.as_raw()) as usize }
     }
 
-    pub fn as_slice(&self) -> &'a [DecodedInst] {
+    pub fn as_slice(&self) -> &'a [DecodedInst<'d>] {
         let ops = unsafe { xed_decoded_inst_operands_const(self.inst.as_raw()) };
         unsafe { std::slice::from_raw_parts(ops as *const DecodedInst, self.len()) }
     }
@@ -242,6 +261,14 @@ impl<'d> DecodedInst<'d> {
     pub fn avx512_dest_elements(&self) -> u32 {
         unsafe { xed_decoded_inst_avx512_dest_elements(self.as_raw()) }
     }
+
+    /// The EVEX-specific details of the instruction, or `None` if it is not
+    /// EVEX encoded.
+    ///
+    /// Legacy instructions promoted to EVEX by APX also return `None`.
+    pub fn evex_info(&self) -> Option<EvexInfo> {
+        EvexInfo::new(self)
+    }
 }
 
 impl<'d> DecodedInst<'d> {
@@ -297,7 +324,9 @@ impl<'d> DecodedInst<'d> {
 impl<'d> DecodedInst<'d> {
     /// Get the instruction form value of this instruction.
     pub fn iform(&self) -> IForm {
-        unsafe { xed_decoded_inst_get_iform_enum(self.as_raw()) }.into()
+        unsafe { xed_decoded_inst_get_iform_enum(self.as_raw()) }
+            .try_into()
+            .expect("iform value was invalid")
     }
 
     /// Get the instruction zero-based iform number based on masking the
@@ -321,7 +350,7 @@ impl<'a, 'd> MemoryOperands<'a, 'd> {
         unsafe { xed_decoded_inst_number_of_memory_operands(self.0.inst.as_raw()) as usize }
     }
 
-    pub fn as_slice(&self) -> &'a [DecodedInst] {
+    pub fn as_slice(&self) -> &'a [DecodedInst<'d>] {
         let ops = unsafe { xed_decoded_inst_operands_const(self.0.inst.as_raw()) };
         unsafe { std::slice::from_raw_parts(ops as *const DecodedInst, self.len()) }
     }
@@ -406,10 +435,19 @@ impl<'a, 'd> MemoryOperand<'a, 'd> {
 }
 
 impl<'d> DecodedInst<'d> {
-    pub fn memory_operands(&self) -> MemoryOperands {
+    pub fn memory_operands(&self) -> MemoryOperands<'_, 'd> {
         MemoryOperands(self.operands())
     }
 
+    /// Every memory access made by this instruction, including implicit ones
+    /// such as the stack accesses of `push` and `call`.
+    ///
+    /// Address computations (`lea`), prefetches, and hint NOPs with a memory
+    /// operand are not included since they do not access memory.
+    pub fn memory_accesses(&self) -> Vec<MemoryAccess> {
+        MemoryAccess::collect(self)
+    }
+
     pub fn branch_displacement(&self) -> i64 {
         unsafe { xed_decoded_inst_get_branch_displacement(self.as_raw()) }
     }
@@ -458,9 +496,7 @@ impl<'d> DecodedInst<'d> {
     /// Get the DFV register if one of the instruction's operands is a "default
     /// flags values" pseudo-register.
     pub fn dfv_reg(&self) -> Option<Register> {
-        unsafe { xed_decoded_inst_get_dfv_reg(self.as_raw()) }
-            .try_into()
-            .ok()
+        get_dfv_reg(self)
     }
 
     /// Get information about the flags, if the instruction uses them.
@@ -512,6 +548,41 @@ impl<'d> DecodedInst<'d> {
     }
 }
 
+// Control flow
+impl<'d> DecodedInst<'d> {
+    /// Classify how this instruction affects the flow of execution.
+    ///
+    /// `address` is the runtime address of the instruction and is used to
+    /// compute the targets of relative branches.
+    ///
+    /// # Example
+    /// ```
+    /// # use xed::*;
+    /// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+    /// // jz 0x1010
+    /// let inst = decode(&[0x74, 0x0e], DecodeOptions::new(state)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     inst.control_flow(0x1000),
+    ///     ControlFlow::ConditionalJump {
+    ///         target: 0x1010,
+    ///         fallthrough: 0x1002,
+    ///     }
+    /// );
+    /// ```
+    pub fn control_flow(&self, address: u64) -> ControlFlow {
+        ControlFlow::classify(self, address)
+    }
+
+    /// Determine how this instruction changes the stack pointer.
+    ///
+    /// The stack pointer is `RSP`, `ESP`, or `SP` depending on
+    /// [`stack_address_mode_bits`](Self::stack_address_mode_bits).
+    pub fn stack_effect(&self) -> StackEffect {
+        StackEffect::analyze(self)
+    }
+}
+
 // Classifiers
 impl<'d> DecodedInst<'d> {
     /// True for APX instructions.
@@ -519,7 +590,7 @@ impl<'d> DecodedInst<'d> {
     /// Includes instructions with EGPRs, REX2, and encodings that eare treated
     /// as illegal on non-APX systems.
     pub fn classify_apx(&self) -> bool {
-        unsafe { xed_classify_apx(self.as_raw()) != 0 }
+        classify_apx(self)
     }
 
     /// True for AMX instructions.
@@ -551,40 +622,313 @@ impl<'d> DecodedInst<'d> {
     }
 }
 
+/// The size of the buffer needed to format any instruction.
+///
+/// Symbol names returned from a [`SymbolResolver`] are limited to 512 bytes by
+/// XED. The rest of the buffer leaves plenty of space for the longest
+/// instructions.
+const FORMAT_BUFFER_LEN: usize = 1024;
+
+/// The largest buffer that is tried before giving up on formatting an
+/// instruction.
+const FORMAT_RETRY_LIMIT: usize = 64 * FORMAT_BUFFER_LEN;
+
 // Formatting
 impl<'d> DecodedInst<'d> {
+    /// The maximum length of the text produced when formatting an
+    /// instruction.
+    ///
+    /// A buffer of this size passed to [`format_into`](Self::format_into) will
+    /// never be too small.
+    pub const MAX_FORMATTED_LEN: usize = FORMAT_BUFFER_LEN - 1;
+
     /// Disassemble this instruction using the specified syntax.
     pub fn disassemble(&self, syntax: Syntax) -> String {
-        let mut buffer = vec![0u8; 32];
+        self.format_owned(new_print_info(syntax, 0))
+    }
 
-        loop {
-            let success = unsafe {
-                xed_format_context(
-                    syntax.into_raw(),
-                    self.as_raw(),
-                    buffer.as_mut_ptr() as *mut std::os::raw::c_char,
-                    buffer.len() as _,
-                    0,
-                    std::ptr::null_mut(),
-                    None,
-                ) != 0
-            };
-
-            if success {
-                break;
-            }
+    /// Disassemble this instruction using the specified syntax and write the
+    /// result to `out`.
+    ///
+    /// This produces the same text as [`disassemble`](Self::disassemble) but
+    /// does not allocate. Returns an error if XED fails to format the
+    /// instruction.
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, syntax: Syntax, out: &mut W) -> fmt::Result {
+        let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+        let text = self
+            .format_generic(new_print_info(syntax, 0), &mut buffer)
+            .ok_or(fmt::Error)?;
 
+        out.write_str(text)
+    }
+
+    /// Disassemble this instruction using the specified syntax into a
+    /// caller-provided buffer.
+    ///
+    /// This produces the same text as [`disassemble`](Self::disassemble) but
+    /// does not allocate. Returns an error if `buffer` is too small to hold the
+    /// result or if XED fails to format the instruction. A buffer of
+    /// [`MAX_FORMATTED_LEN`](Self::MAX_FORMATTED_LEN) bytes is always large
+    /// enough.
+    pub fn format_into<'b>(
+        &self,
+        syntax: Syntax,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b str, DisassembleError> {
+        let mut temp = [0u8; FORMAT_BUFFER_LEN];
+        let text = self
+            .format_generic(new_print_info(syntax, 0), &mut temp)
+            .ok_or(DisassembleError::format_failed())?;
+        let output = buffer
+            .get_mut(..text.len())
+            .ok_or(DisassembleError::new(text.len()))?;
+        output.copy_from_slice(text.as_bytes());
+
+        // SAFETY: We just copied the bytes from a valid str.
+        Ok(unsafe { std::str::from_utf8_unchecked(output) })
+    }
+
+    /// Get an adapter that implements [`Display`](fmt::Display) by
+    /// disassembling this instruction using the specified syntax.
+    pub fn display(&self, syntax: Syntax) -> Disassembly<'_, 'd> {
+        Disassembly { inst: self, syntax }
+    }
+
+    /// Disassemble this instruction using the specified syntax, resolving
+    /// addresses to symbols using `resolver`.
+    ///
+    /// `runtime_address` is the address at which this instruction is located.
+    /// It is used to compute the targets of relative branches and RIP-relative
+    /// memory operands before they are passed to `resolver`.
+    ///
+    /// Resolved addresses are replaced by the symbol name. Use
+    /// [`format_with`](Self::format_with) to print XED's `0x2010 <printf+0x10>`
+    /// annotations instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use xed::*;
+    /// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+    /// let symbols = BTreeMap::from([(0x2000, "printf")]);
+    ///
+    /// // call 0x2010
+    /// let bytes = [0xe8, 0x0b, 0x10, 0x00, 0x00];
+    /// let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     inst.disassemble_with(Syntax::INTEL, 0x1000, &symbols),
+    ///     "call printf+0x10"
+    /// );
+    /// ```
+    pub fn disassemble_with<R: SymbolResolver>(
+        &self,
+        syntax: Syntax,
+        runtime_address: u64,
+        resolver: &R,
+    ) -> String {
+        let options = FormatOptions::new(syntax)
+            .runtime_address(runtime_address)
+            .address_before_symbol(false);
+
+        let resolver = RecordingResolver::new(resolver);
+
+        let mut info = options.print_info();
+        info.context = &resolver as *const RecordingResolver<R> as *mut c_void;
+        info.disassembly_callback = Some(symbol_callback::<RecordingResolver<R>>);
+
+        let text = self.format_owned(info);
+        inline_symbols(tokenize(
+            self,
+            &text,
+            &options,
+            &resolver.into_annotations(),
+        ))
+    }
+
+    /// Format this instruction according to the provided [`FormatOptions`].
+    pub fn format(&self, options: &FormatOptions) -> String {
+        self.format_postprocess(options, options.print_info())
+    }
+
+    /// Format this instruction according to the provided [`FormatOptions`],
+    /// resolving addresses to symbols using `resolver`.
+    pub fn format_with<R: SymbolResolver>(&self, options: &FormatOptions, resolver: &R) -> String {
+        let mut info = options.print_info();
+        info.context = resolver as *const R as *mut c_void;
+        info.disassembly_callback = Some(symbol_callback::<R>);
+
+        self.format_postprocess(options, info)
+    }
+
+    /// Format this instruction according to the provided [`FormatOptions`] and
+    /// split the result into [`Token`]s.
+    pub fn tokenize(&self, options: &FormatOptions) -> Vec<Token> {
+        tokenize(self, &self.format_owned(options.print_info()), options, &[])
+    }
+
+    /// Format this instruction according to the provided [`FormatOptions`],
+    /// resolving addresses to symbols using `resolver`, and split the result
+    /// into [`Token`]s.
+    pub fn tokenize_with<R: SymbolResolver>(
+        &self,
+        options: &FormatOptions,
+        resolver: &R,
+    ) -> Vec<Token> {
+        let mut info = options.print_info();
+        info.context = resolver as *const R as *mut c_void;
+        info.disassembly_callback = Some(symbol_callback::<R>);
+
+        tokenize(self, &self.format_owned(info), options, &[])
+    }
+
+    fn format_postprocess(&self, options: &FormatOptions, info: xed_print_info_t) -> String {
+        let text = self.format_owned(info);
+
+        if options.dialect.is_some() {
+            tokenize(self, &text, options, &[])
+                .iter()
+                .map(|token| token.text())
+                .collect()
+        } else if options.needs_postprocess() {
+            options.postprocess(&text)
+        } else {
+            text
+        }
+    }
+
+    /// Format into a new `String`, retrying with larger buffers if XED fails.
+    ///
+    /// # Panics
+    /// Panics if XED still fails with a buffer of [`FORMAT_RETRY_LIMIT`]
+    /// bytes, which only happens if the instruction is not valid.
+    fn format_owned(&self, info: xed_print_info_t) -> String {
+        let mut buffer = [0u8; FORMAT_BUFFER_LEN];
+        if let Some(text) = self.format_generic(info, &mut buffer) {
+            return text.to_owned();
+        }
+
+        let mut buffer = vec![0u8; FORMAT_BUFFER_LEN];
+        loop {
+            assert!(
+                buffer.len() < FORMAT_RETRY_LIMIT,
+                "XED failed to format the instruction"
+            );
             buffer.resize(buffer.len() * 2, 0);
+
+            if let Some(len) = self.format_raw(info, &mut buffer) {
+                return buffer_text(&buffer[..len]).to_owned();
+            }
         }
+    }
+
+    /// Format into `buffer`, returning `None` if XED fails.
+    fn format_generic<'b>(&self, info: xed_print_info_t, buffer: &'b mut [u8]) -> Option<&'b str> {
+        let len = self.format_raw(info, buffer)?;
+        Some(buffer_text(&buffer[..len]))
+    }
 
-        if let Some(index) = buffer.iter().position(|&b| b == 0) {
-            buffer.truncate(index);
+    /// Format into `buffer` and return the length of the output, or `None` if
+    /// XED fails (e.g. because the buffer is too small).
+    fn format_raw(&self, mut info: xed_print_info_t, buffer: &mut [u8]) -> Option<usize> {
+        info.p = self.as_raw();
+        info.buf = buffer.as_mut_ptr() as *mut std::os::raw::c_char;
+        info.blen = buffer.len() as _;
+        buffer[0] = 0;
+
+        if unsafe { xed_format_generic(&mut info) } == 0 {
+            return None;
         }
 
-        match String::from_utf8_lossy(&buffer) {
-            Cow::Owned(text) => text,
-            // SAFETY: from_utf8_lossy just verified that the string is valid utf8
-            Cow::Borrowed(_) => unsafe { String::from_utf8_unchecked(buffer) },
+        Some(buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len()))
+    }
+}
+
+/// The text within a buffer filled by [`DecodedInst::format_raw`].
+fn buffer_text(buffer: &[u8]) -> &str {
+    match std::str::from_utf8(buffer) {
+        Ok(text) => text,
+        // XED itself only emits ASCII and symbol names are truncated on a
+        // char boundary so this should never happen.
+        Err(e) => std::str::from_utf8(&buffer[..e.valid_up_to()]).unwrap(),
+    }
+}
+
+/// Adapter for displaying the disassembly of an instruction.
+///
+/// This is created by [`DecodedInst::display`].
+#[derive(Copy, Clone)]
+pub struct Disassembly<'a, 'd> {
+    inst: &'a DecodedInst<'d>,
+    syntax: Syntax,
+}
+
+impl fmt::Display for Disassembly<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inst.write_to(self.syntax, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    #[test]
+    fn formatting_matches_disassemble() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let insts: &[&[u8]] = &[
+            // nop
+            &[0x90],
+            // vfmadd231ps zmm0{k1}, zmm1, dword ptr [rax+rbx*8+0x12345678]{1to16}
+            &[
+                0x62, 0xf2, 0x75, 0x59, 0xb8, 0x84, 0xd8, 0x78, 0x56, 0x34, 0x12,
+            ],
+        ];
+
+        for bytes in insts {
+            let inst = decode(bytes, DecodeOptions::new(state)).unwrap();
+
+            for syntax in [Syntax::INTEL, Syntax::ATT, Syntax::XED] {
+                let expected = inst.disassemble(syntax);
+                let mut buffer = [0u8; DecodedInst::MAX_FORMATTED_LEN];
+                let mut written = String::new();
+                inst.write_to(syntax, &mut written).unwrap();
+
+                assert_eq!(inst.display(syntax).to_string(), expected);
+                assert_eq!(inst.format_into(syntax, &mut buffer).unwrap(), expected);
+                assert_eq!(written, expected);
+            }
         }
     }
+
+    #[test]
+    fn format_into_small_buffer() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // add rax, rbx
+        let inst = decode(&[0x48, 0x01, 0xd8], DecodeOptions::new(state)).unwrap();
+
+        let mut buffer = [0u8; 12];
+        assert_eq!(
+            inst.format_into(Syntax::INTEL, &mut buffer).unwrap(),
+            "add rax, rbx"
+        );
+
+        let mut buffer = [0u8; 11];
+        let err = inst.format_into(Syntax::INTEL, &mut buffer).unwrap_err();
+        assert_eq!(err.required_len(), Some(12));
+    }
+
+    #[test]
+    fn format_failure_is_an_error() {
+        // XED cannot format an instruction that was never decoded.
+        let inst = unsafe { DecodedInst::from_raw(std::mem::zeroed()) };
+
+        let mut buffer = [0u8; DecodedInst::MAX_FORMATTED_LEN];
+        let err = inst.format_into(Syntax::INTEL, &mut buffer).unwrap_err();
+        assert_eq!(err.required_len(), None);
+
+        let mut text = String::new();
+        assert!(inst.write_to(Syntax::INTEL, &mut text).is_err());
+    }
 }
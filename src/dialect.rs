@@ -0,0 +1,682 @@
+use super::{Category, DecodedInst, FormatOptions, Register, Token, TokenKind};
+
+/// An assembler whose syntax can be produced by [`FormatOptions::dialect`].
+///
+/// Both dialects are based on XED's Intel syntax output with the differences
+/// that would stop the assembler from accepting it rewritten:
+///
+/// - Size keywords and `ptr` are adjusted to what the assembler expects.
+/// - RIP-relative memory operands and relative branch targets are printed
+///   relative to the start of the instruction (`$`) or, if one was resolved, to
+///   a symbol.
+/// - String instructions such as `movsd` are printed without operands so that
+///   they are not confused with the SSE instructions of the same name.
+/// - Implicit x87 `st` operands are dropped and embedded rounding is printed
+///   where the assembler expects it.
+/// - Negative signed immediates are printed as negative numbers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Dialect {
+    /// The Netwide Assembler.
+    Nasm,
+
+    /// The Microsoft Macro Assembler.
+    Masm,
+}
+
+/// A single operand of the instruction being rewritten.
+struct Operand {
+    tokens: Vec<Token>,
+
+    /// The symbol name and offset that XED printed after the operand.
+    symbol: Option<(String, u64)>,
+}
+
+impl Operand {
+    fn new(mut tokens: Vec<Token>) -> Self {
+        let symbol = match tokens.last() {
+            Some(token) if token.kind() == TokenKind::Symbol => {
+                let symbol = parse_symbol(token.text());
+                tokens.pop();
+                symbol
+            }
+            _ => None,
+        };
+
+        Self { tokens, symbol }
+    }
+
+    fn is_memory(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|t| t.kind() == TokenKind::MemoryStart)
+    }
+
+    fn is_branch_target(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|t| matches!(t.kind(), TokenKind::BranchTarget(_)))
+    }
+
+    fn is_immediate(&self) -> bool {
+        matches!(self.tokens[..], [ref token] if matches!(token.kind(), TokenKind::Number(_)))
+    }
+
+    /// Whether this is the implicit `st0` operand of an x87 instruction, which
+    /// XED prints as `st`.
+    fn is_implicit_st0(&self) -> bool {
+        matches!(self.tokens[..], [ref token] if token.text() == "st")
+    }
+
+    fn is_st_register(&self) -> bool {
+        match self.tokens.first().map(|t| t.kind()) {
+            Some(TokenKind::Register(reg)) => format!("{reg:?}").starts_with("ST("),
+            _ => false,
+        }
+    }
+
+    fn segment(&self) -> Option<&Token> {
+        let start = self
+            .tokens
+            .iter()
+            .position(|t| t.kind() == TokenKind::MemoryStart)?;
+
+        self.tokens[..start]
+            .iter()
+            .find(|t| matches!(t.kind(), TokenKind::Register(_)))
+    }
+}
+
+/// Parse a symbol printed by XED as `<name+0xoffset>`.
+fn parse_symbol(text: &str) -> Option<(String, u64)> {
+    let text = text.strip_prefix('<')?.strip_suffix('>')?;
+
+    if let Some((name, offset)) = text.rsplit_once("+0x") {
+        if let Ok(offset) = u64::from_str_radix(offset, 16) {
+            return Some((name.to_owned(), offset));
+        }
+    }
+
+    Some((text.to_owned(), 0))
+}
+
+fn keyword(text: &str) -> Token {
+    Token::new(TokenKind::Keyword, text)
+}
+
+fn punct(text: &str) -> Token {
+    Token::new(TokenKind::Punctuation, text)
+}
+
+fn space() -> Token {
+    Token::new(TokenKind::Whitespace, " ")
+}
+
+struct Rewriter<'a, 'd> {
+    inst: &'a DecodedInst<'d>,
+    dialect: Dialect,
+    options: &'a FormatOptions,
+}
+
+impl Rewriter<'_, '_> {
+    fn number(&self, value: u64) -> Token {
+        Token::new(TokenKind::Number(value), self.options.hex(value))
+    }
+
+    /// `+value` or `-value`, or nothing if `value` is zero.
+    fn signed(&self, value: i64) -> Vec<Token> {
+        match value {
+            0 => vec![],
+            _ if value < 0 => vec![punct("-"), self.number(value.unsigned_abs())],
+            _ => vec![punct("+"), self.number(value as u64)],
+        }
+    }
+
+    /// An address relative to the start of the instruction, or to `symbol` if
+    /// XED resolved one.
+    fn relative(&self, symbol: &Option<(String, u64)>, offset: i64) -> Vec<Token> {
+        let mut tokens = Vec::new();
+
+        match symbol {
+            Some((name, offset)) => {
+                tokens.push(Token::new(TokenKind::Symbol, name.as_str()));
+                tokens.extend(self.signed(*offset as i64));
+            }
+            None => {
+                tokens.push(punct("$"));
+                tokens.extend(self.signed(offset));
+            }
+        }
+
+        tokens
+    }
+
+    fn memory(&self, operand: &Operand, index: usize) -> Vec<Token> {
+        let tokens = &operand.tokens;
+        let start = tokens
+            .iter()
+            .position(|t| t.kind() == TokenKind::MemoryStart)
+            .unwrap();
+        let end = tokens
+            .iter()
+            .position(|t| t.kind() == TokenKind::MemoryEnd)
+            .unwrap_or(tokens.len());
+
+        let before = &tokens[..start];
+        let inner = &tokens[start + 1..end];
+        let after = tokens.get(end + 1..).unwrap_or_default();
+
+        let (length, displacement) = match self.inst.memory_operands().get(index) {
+            Some(memop) => (memop.operand_length(), memop.displacement()),
+            None => (0, 0),
+        };
+
+        // XED has no name for 80-bit memory operands and prints a bare `ptr`.
+        let size = before
+            .iter()
+            .find(|t| t.kind() == TokenKind::Keyword && t.text() != "ptr")
+            .map(|t| t.text())
+            .or_else(|| (length == 10).then_some("tbyte"));
+        let segment = operand.segment();
+        let broadcast = after.iter().any(|t| t.text().starts_with("1to"));
+        let rip_relative = matches!(
+            inner.first().map(|t| t.kind()),
+            Some(TokenKind::Register(Register::RIP))
+        );
+        let absolute = !inner
+            .iter()
+            .any(|t| matches!(t.kind(), TokenKind::Register(_)));
+
+        let mut output = Vec::new();
+        match self.dialect {
+            Dialect::Nasm => {
+                if let Some(size) = size {
+                    let size = match size {
+                        "tbyte" => "tword",
+                        "xmmword" => "oword",
+                        "ymmword" => "yword",
+                        "zmmword" => "zword",
+                        size => size,
+                    };
+
+                    output.extend([keyword(size), space()]);
+                }
+
+                output.push(Token::new(TokenKind::MemoryStart, "["));
+
+                if rip_relative {
+                    output.extend([keyword("rel"), space()]);
+                }
+
+                if let Some(segment) = segment {
+                    output.extend([segment.clone(), punct(":")]);
+                }
+            }
+            Dialect::Masm => {
+                if let Some(size) = size {
+                    let ptr = if broadcast { "bcst" } else { "ptr" };
+                    output.extend([keyword(size), space(), keyword(ptr), space()]);
+                }
+
+                // MASM treats a bracketed constant as an immediate unless it
+                // has a segment.
+                match segment {
+                    Some(segment) => output.extend([segment.clone(), punct(":")]),
+                    None if absolute => output.extend([
+                        Token::new(TokenKind::Register(Register::DS), "ds"),
+                        punct(":"),
+                    ]),
+                    None => (),
+                }
+
+                output.push(Token::new(TokenKind::MemoryStart, "["));
+            }
+        }
+
+        if rip_relative {
+            let offset = self.inst.length() as i64 + displacement;
+
+            output.extend(self.relative(&operand.symbol, offset));
+        } else {
+            output.extend(inner.iter().cloned());
+        }
+
+        output.push(Token::new(TokenKind::MemoryEnd, "]"));
+
+        let mut after = after.iter();
+        while let Some(token) = after.next() {
+            // MASM uses `bcst` instead of `{1toN}`.
+            if self.dialect == Dialect::Masm && broadcast && token.text() == "{" {
+                let group: Vec<_> = after.by_ref().take_while(|t| t.text() != "}").collect();
+                if group.iter().any(|t| t.text().starts_with("1to")) {
+                    continue;
+                }
+
+                output.push(token.clone());
+                output.extend(group.into_iter().cloned());
+                output.push(punct("}"));
+                continue;
+            }
+
+            output.push(token.clone());
+        }
+
+        output
+    }
+
+    /// Rewrite a register operand, removing and returning any embedded
+    /// rounding or SAE decorator.
+    fn register(&self, operand: &Operand) -> (Vec<Token>, Option<Vec<Token>>) {
+        let mut output = Vec::new();
+        let mut rounding = None;
+        let mut tokens = operand.tokens.iter();
+
+        while let Some(token) = tokens.next() {
+            match token.kind() {
+                TokenKind::Register(reg) if self.dialect == Dialect::Nasm => {
+                    // NASM names x87 registers `st0` through `st7`.
+                    let text = format!("{reg:?}").to_ascii_lowercase();
+                    match text.strip_prefix("st(") {
+                        Some(n) => output.push(Token::new(
+                            token.kind(),
+                            format!("st{}", n.trim_end_matches(')')),
+                        )),
+                        None => output.push(token.clone()),
+                    }
+                }
+                TokenKind::Punctuation if token.text() == "{" => {
+                    let mut group = vec![token.clone()];
+                    for token in tokens.by_ref() {
+                        group.push(token.clone());
+                        if token.text() == "}" {
+                            break;
+                        }
+                    }
+
+                    if group.iter().any(|t| t.text() == "sae") {
+                        // XED calls round-to-nearest `rne` while the
+                        // assemblers call it `rn`.
+                        for token in &mut group {
+                            if token.text() == "rne" {
+                                *token = keyword("rn");
+                            }
+                        }
+
+                        rounding = Some(group);
+                    } else {
+                        output.extend(group);
+                    }
+                }
+                _ => output.push(token.clone()),
+            }
+        }
+
+        (output, rounding)
+    }
+
+    fn rewrite(&self, tokens: Vec<Token>) -> Vec<Token> {
+        let mut prefixes = Vec::new();
+        let mut mnemonic = None;
+        let mut operands = Vec::new();
+        let mut current = Vec::new();
+
+        for token in tokens {
+            match token.kind() {
+                TokenKind::Whitespace => (),
+                TokenKind::Prefix => prefixes.push(token),
+                TokenKind::Mnemonic => mnemonic = Some(token),
+                TokenKind::Punctuation if token.text() == "," => {
+                    operands.push(Operand::new(std::mem::take(&mut current)));
+                }
+                _ => current.push(token),
+            }
+        }
+
+        if !current.is_empty() {
+            operands.push(Operand::new(current));
+        }
+
+        let mut mnemonic = match mnemonic {
+            Some(mnemonic) => mnemonic,
+            None => return vec![],
+        };
+
+        let mut prefixes: Vec<Token> = prefixes
+            .into_iter()
+            .filter_map(|prefix| self.prefix(prefix))
+            .collect();
+
+        let category = self.inst.category();
+        if category == Category::STRINGOP || category == Category::IOSTRINGOP {
+            let segment = operands.iter().find_map(|op| op.segment()).cloned();
+            let address_size = self.address_size_prefix();
+
+            match self.dialect {
+                Dialect::Nasm => {
+                    if let Some(segment) = segment {
+                        prefixes.push(Token::new(TokenKind::Prefix, segment.text()));
+                    }
+
+                    if let Some(prefix) = address_size {
+                        prefixes.push(Token::new(TokenKind::Prefix, prefix));
+                    }
+
+                    operands.clear();
+                }
+                // MASM has no way to spell these overrides without operands so
+                // use the generic form of the mnemonic instead.
+                Dialect::Masm if segment.is_some() || address_size.is_some() => {
+                    let text = mnemonic.text();
+                    mnemonic = Token::new(TokenKind::Mnemonic, &text[..text.len() - 1]);
+                }
+                Dialect::Masm => operands.clear(),
+            }
+        }
+
+        let has_memory = operands.iter().any(|op| op.is_memory());
+        let has_st_register = operands.iter().any(|op| op.is_st_register());
+
+        let mut rounding = None;
+        let mut memory_index = 0;
+        let mut seen_immediate = false;
+        let mut rewritten = Vec::new();
+
+        for operand in &operands {
+            if operand.is_implicit_st0() {
+                let keep = self.dialect == Dialect::Masm && !has_memory && has_st_register;
+                if !keep {
+                    continue;
+                }
+            }
+
+            let (tokens, is_immediate) = if operand.is_memory() {
+                memory_index += 1;
+                (self.memory(operand, memory_index - 1), false)
+            } else if operand.is_branch_target() {
+                let offset = self.inst.length() as i64 + self.inst.branch_displacement();
+                (self.relative(&operand.symbol, offset), false)
+            } else if operand.is_immediate() {
+                let first = !seen_immediate;
+                seen_immediate = true;
+
+                // Only immediates that get sign-extended to a wider operand are
+                // printed as negative, others are left as XED printed them.
+                let value = self.inst.signed_immediate();
+                let extended = self.inst.immediate_width_bits() < self.inst.operand_width();
+                if first && self.inst.immediate_is_signed() && extended && value < 0 {
+                    let value = (value as i64).unsigned_abs();
+                    (vec![punct("-"), self.number(value)], true)
+                } else {
+                    (operand.tokens.clone(), true)
+                }
+            } else {
+                let (tokens, decorator) = self.register(operand);
+                rounding = rounding.or(decorator);
+                (tokens, false)
+            };
+
+            rewritten.push((tokens, is_immediate));
+        }
+
+        if let Some(rounding) = rounding {
+            let position = rewritten
+                .iter()
+                .position(|(_, is_immediate)| *is_immediate)
+                .unwrap_or(rewritten.len());
+
+            match self.dialect {
+                // NASM takes rounding as a separate operand.
+                Dialect::Nasm => rewritten.insert(position, (rounding, false)),
+                // MASM attaches it to the last register operand.
+                Dialect::Masm => match position.checked_sub(1) {
+                    Some(last) => rewritten[last].0.extend(rounding),
+                    None => rewritten.insert(0, (rounding, false)),
+                },
+            }
+        }
+
+        let mut output = Vec::new();
+        for prefix in prefixes {
+            output.extend([prefix, space()]);
+        }
+
+        output.push(mnemonic);
+
+        for (i, (tokens, _)) in rewritten.into_iter().enumerate() {
+            if i == 0 {
+                output.push(space());
+            } else {
+                output.extend([punct(","), space()]);
+            }
+
+            output.extend(tokens);
+        }
+
+        if self.dialect == Dialect::Masm {
+            for token in &mut output {
+                if let TokenKind::Number(_) = token.kind() {
+                    if let Some(text) = masm_hex(token.text()) {
+                        *token = Token::new(token.kind(), text);
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    fn prefix(&self, prefix: Token) -> Option<Token> {
+        let text = match (self.dialect, prefix.text()) {
+            (Dialect::Nasm, "addr16") => "a16",
+            (Dialect::Nasm, "addr32") => "a32",
+            (Dialect::Nasm, "data16") => "o16",
+            (Dialect::Nasm, "data32") => "o32",
+            // Branch hints are encoded as segment overrides.
+            (Dialect::Nasm, "hint-taken") => "ds",
+            (Dialect::Nasm, "hint-not-taken") => "cs",
+            (Dialect::Masm, "hint-taken" | "hint-not-taken") => return None,
+            _ => return Some(prefix),
+        };
+
+        Some(Token::new(TokenKind::Prefix, text))
+    }
+
+    /// The NASM prefix needed to override the address size of a string
+    /// instruction, if any.
+    fn address_size_prefix(&self) -> Option<&'static str> {
+        let width = self.inst.memory_operands().get(0)?.address_width();
+        let default = match self.inst.machine_mode_bits() {
+            16 => 16,
+            32 => 32,
+            _ => 64,
+        };
+
+        match width {
+            _ if width == default => None,
+            16 => Some("a16"),
+            32 => Some("a32"),
+            _ => None,
+        }
+    }
+}
+
+/// Convert a `0x` hex number into MASM's `h` suffixed form.
+fn masm_hex(text: &str) -> Option<String> {
+    let digits = text.strip_prefix("0x")?;
+
+    // Numbers must start with a digit to not be parsed as an identifier.
+    let zero = if digits.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        "0"
+    } else {
+        ""
+    };
+
+    Some(format!("{zero}{digits}h"))
+}
+
+/// Rewrite tokens from XED's Intel syntax output into `dialect`.
+pub(crate) fn rewrite(
+    inst: &DecodedInst,
+    tokens: Vec<Token>,
+    dialect: Dialect,
+    options: &FormatOptions,
+) -> Vec<Token> {
+    let rewriter = Rewriter {
+        inst,
+        dialect,
+        options,
+    };
+
+    rewriter.rewrite(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State, Syntax};
+
+    fn check(dialect: Dialect, cases: &[(&[u8], &str)]) {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let options = FormatOptions::new(Syntax::INTEL).dialect(Some(dialect));
+
+        for &(bytes, expected) in cases {
+            let inst = decode(bytes, DecodeOptions::new(state)).unwrap();
+            assert_eq!(inst.format(&options), expected, "{bytes:02x?}");
+        }
+    }
+
+    #[test]
+    fn nasm_golden() {
+        check(
+            Dialect::Nasm,
+            &[
+                (&[0x48, 0x8b, 0x45, 0xf8], "mov rax, qword [rbp-0x8]"),
+                (
+                    &[0x48, 0x8b, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+                    "mov rax, qword [rel $+0x1002]",
+                ),
+                (
+                    &[0x48, 0x8d, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+                    "lea rax, [rel $+0x1002]",
+                ),
+                (
+                    &[0x64, 0x48, 0x8b, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00],
+                    "mov rax, qword [fs:0x28]",
+                ),
+                (&[0xf3, 0xab], "rep stosd"),
+                (&[0x64, 0xa5], "fs movsd"),
+                (&[0x67, 0xa5], "a32 movsd"),
+                (&[0xf2, 0x0f, 0x10, 0x0c, 0x24], "movsd xmm1, qword [rsp]"),
+                (&[0x66, 0x0f, 0x6f, 0x00], "movdqa xmm0, oword [rax]"),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x48, 0x58, 0x04, 0x24],
+                    "vaddps zmm0, zmm0, zword [rsp]",
+                ),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x58, 0x58, 0x04, 0x24],
+                    "vaddps zmm0, zmm0, dword [rsp]{1to16}",
+                ),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x18, 0x58, 0xc1],
+                    "vaddps zmm0, zmm0, zmm1, {rn-sae}",
+                ),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x58, 0xc2, 0xc1, 0x00],
+                    "vcmpps k0, zmm0, zmm1, {sae}, 0x0",
+                ),
+                (&[0xdb, 0x2c, 0x24], "fld tword [rsp]"),
+                (&[0xd9, 0xc1], "fld st1"),
+                (&[0xde, 0xc1], "faddp st1"),
+                (&[0xd9, 0xe8], "fld1"),
+                (&[0xdd, 0x5c, 0x24, 0x08], "fstp qword [rsp+0x8]"),
+                (&[0xeb, 0x10], "jmp $+0x12"),
+                (&[0xeb, 0xfe], "jmp $"),
+                (&[0x3e, 0x74, 0x00], "ds jz $+0x3"),
+                (&[0x6a, 0x80], "push -0x80"),
+                (&[0x48, 0x83, 0xc0, 0xf0], "add rax, -0x10"),
+                (&[0x0f, 0xae, 0x00], "fxsave [rax]"),
+            ],
+        );
+    }
+
+    #[test]
+    fn masm_golden() {
+        check(
+            Dialect::Masm,
+            &[
+                (&[0x48, 0x8b, 0x45, 0xf8], "mov rax, qword ptr [rbp-8h]"),
+                (
+                    &[0x48, 0x8b, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+                    "mov rax, qword ptr [$+1002h]",
+                ),
+                (
+                    &[0x48, 0x8d, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+                    "lea rax, [$+1002h]",
+                ),
+                (
+                    &[0x64, 0x48, 0x8b, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00],
+                    "mov rax, qword ptr fs:[28h]",
+                ),
+                (
+                    &[0xff, 0x24, 0x25, 0xf0, 0x00, 0x00, 0x00],
+                    "jmp qword ptr ds:[0f0h]",
+                ),
+                (&[0xf3, 0xab], "rep stosd"),
+                (&[0x64, 0xa5], "movs dword ptr [rdi], dword ptr fs:[rsi]"),
+                (
+                    &[0xf2, 0x0f, 0x10, 0x0c, 0x24],
+                    "movsd xmm1, qword ptr [rsp]",
+                ),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x58, 0x58, 0x04, 0x24],
+                    "vaddps zmm0, zmm0, dword bcst [rsp]",
+                ),
+                (
+                    &[0x62, 0xf1, 0x7c, 0x18, 0x58, 0xc1],
+                    "vaddps zmm0, zmm0, zmm1{rn-sae}",
+                ),
+                (&[0xdb, 0x2c, 0x24], "fld tbyte ptr [rsp]"),
+                (&[0xd9, 0xc1], "fld st, st(1)"),
+                (&[0xd9, 0xe8], "fld1"),
+                (&[0xdd, 0x5c, 0x24, 0x08], "fstp qword ptr [rsp+8h]"),
+                (&[0xeb, 0x10], "jmp $+12h"),
+                (&[0x3e, 0x74, 0x00], "jz $+3h"),
+                (&[0x6a, 0x80], "push -80h"),
+                (
+                    &[0x66, 0xc7, 0x00, 0x00, 0xff],
+                    "mov word ptr [rax], 0ff00h",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn symbols() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let symbols = BTreeMap::from([(0x2000, "printf")]);
+        let options = FormatOptions::new(Syntax::INTEL)
+            .runtime_address(0x1000)
+            .uppercase(true);
+
+        // call 0x2010
+        let call = decode(&[0xe8, 0x0b, 0x10, 0x00, 0x00], DecodeOptions::new(state)).unwrap();
+        // lea rax, [rip+0xffb]
+        let lea = decode(
+            &[0x48, 0x8d, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+            DecodeOptions::new(state),
+        )
+        .unwrap();
+
+        let nasm = options.dialect(Some(Dialect::Nasm));
+        assert_eq!(call.format_with(&nasm, &symbols), "CALL printf+0x10");
+        assert_eq!(
+            lea.format_with(&nasm, &symbols),
+            "LEA RAX, [REL printf+0x2]"
+        );
+
+        let masm = options.dialect(Some(Dialect::Masm));
+        assert_eq!(call.format_with(&masm, &symbols), "CALL printf+10h");
+        assert_eq!(lea.format_with(&masm, &symbols), "LEA RAX, [printf+2h]");
+    }
+}
@@ -110,11 +110,73 @@ impl<T: fmt::Debug> fmt::Display for InvalidEnumValue<T> {
 
 impl<T: fmt::Debug> std::error::Error for InvalidEnumValue<T> {}
 
-// #[derive(Copy, Clone, Debug)]
-// pub struct DisassembleError(());
-
-// impl fmt::Display for DisassembleError {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         f.write_str("")
-//     }
-// }
+/// Error for when [`DecodedInst::format_into`] cannot disassemble an
+/// instruction, either because the buffer is too small to hold the output or
+/// because XED failed to format the instruction.
+///
+/// [`DecodedInst::format_into`]: crate::DecodedInst::format_into
+#[derive(Copy, Clone, Debug)]
+pub struct DisassembleError {
+    required: Option<usize>,
+}
+
+impl DisassembleError {
+    pub(crate) fn new(required: usize) -> Self {
+        Self {
+            required: Some(required),
+        }
+    }
+
+    pub(crate) fn format_failed() -> Self {
+        Self { required: None }
+    }
+
+    /// The size of buffer that would have been needed to hold the output, or
+    /// `None` if XED failed to format the instruction.
+    pub fn required_len(&self) -> Option<usize> {
+        self.required
+    }
+}
+
+impl fmt::Display for DisassembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.required {
+            Some(required) => write!(
+                f,
+                "buffer too small to hold the disassembled instruction ({required} bytes required)"
+            ),
+            None => f.write_str("XED failed to format the instruction"),
+        }
+    }
+}
+
+impl std::error::Error for DisassembleError {}
+
+/// Error for when the CPU features of the host machine cannot be detected
+/// because it is not an x86 machine.
+#[derive(Copy, Clone, Debug)]
+pub struct UnsupportedHostError {
+    arch: &'static str,
+}
+
+impl UnsupportedHostError {
+    #[allow(dead_code)]
+    pub(crate) fn new() -> Self {
+        Self {
+            arch: std::env::consts::ARCH,
+        }
+    }
+
+    /// The architecture of the host machine.
+    pub fn arch(&self) -> &str {
+        self.arch
+    }
+}
+
+impl fmt::Display for UnsupportedHostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot detect x86 CPU features on a {} host", self.arch)
+    }
+}
+
+impl std::error::Error for UnsupportedHostError {}
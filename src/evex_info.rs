@@ -0,0 +1,253 @@
+use xed_sys::*;
+
+use super::{DecodedInst, Extension, Register};
+
+/// The value of XED's `VEXVALID` operand for EVEX encoded instructions.
+const VEXVALID_EVEX: u32 = 2;
+
+/// An embedded rounding mode of an EVEX instruction.
+///
+/// Embedded rounding always implies suppress-all-exceptions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even (`{rne-sae}`).
+    Nearest,
+
+    /// Round down towards negative infinity (`{rd-sae}`).
+    Down,
+
+    /// Round up towards positive infinity (`{ru-sae}`).
+    Up,
+
+    /// Round towards zero (`{rz-sae}`).
+    TowardZero,
+}
+
+/// The EVEX-specific details of an AVX512 instruction.
+///
+/// Returned by [`DecodedInst::evex_info`].
+///
+/// # Example
+/// ```
+/// # use xed::*;
+/// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+/// // vaddps zmm0{k1}{z}, zmm1, dword ptr [rax+0x40]{1to16}
+/// let bytes = [0x62, 0xf1, 0x74, 0xd9, 0x58, 0x40, 0x10];
+/// let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+///
+/// let evex = inst.evex_info().unwrap();
+/// assert_eq!(evex.mask(), Some(Register::K1));
+/// assert!(evex.is_zeroing());
+/// assert_eq!(evex.broadcast(), Some(16));
+/// assert_eq!(evex.disp8_scale(), Some(4));
+/// assert_eq!(evex.rounding(), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EvexInfo {
+    rounding: Option<RoundingMode>,
+    sae: bool,
+    broadcast: Option<u32>,
+    mask: Option<Register>,
+    zeroing: bool,
+    disp8_scale: Option<u32>,
+    vector_length_bits: u32,
+    vector_length_ignored: bool,
+}
+
+impl EvexInfo {
+    pub(crate) fn new(inst: &DecodedInst) -> Option<Self> {
+        let raw = inst.as_raw();
+
+        // APX promotes legacy instructions to EVEX, but reuses the EVEX
+        // payload bits for its own purposes so none of these details apply.
+        if unsafe { xed3_operand_get_vexvalid(raw) } != VEXVALID_EVEX
+            || inst.extension() == Extension::APXEVEX
+        {
+            return None;
+        }
+
+        let rounding = match unsafe { xed3_operand_get_roundc(raw) } {
+            1 => Some(RoundingMode::Nearest),
+            2 => Some(RoundingMode::Down),
+            3 => Some(RoundingMode::Up),
+            4 => Some(RoundingMode::TowardZero),
+            _ => None,
+        };
+
+        let broadcast = if inst.uses_embedded_braocast() {
+            broadcast_factor(unsafe { xed3_operand_get_bcast(raw) })
+        } else {
+            None
+        };
+
+        let mask = match unsafe { xed3_operand_get_mask(raw) } {
+            0 => None,
+            n => Register::from_raw(Register::K0.into_raw() + n),
+        };
+
+        // N is the size in bytes of the memory accessed by a single
+        // "element" of the tuple type (e.g. a single element for broadcasts
+        // and the full vector for full-vector loads).
+        let disp8_scale = if inst.memory_operands().is_empty() {
+            None
+        } else {
+            let nelem = unsafe { xed3_operand_get_nelem(raw) };
+            let element_size = unsafe { xed3_operand_get_element_size(raw) };
+            let bits = nelem * element_size;
+
+            (bits != 0).then_some(bits / 8)
+        };
+
+        Some(Self {
+            rounding,
+            sae: unsafe { xed3_operand_get_sae(raw) } != 0,
+            broadcast,
+            mask,
+            zeroing: unsafe { xed3_operand_get_zeroing(raw) } != 0,
+            disp8_scale,
+            vector_length_bits: inst.vector_length_bits(),
+            vector_length_ignored: unsafe { xed3_operand_get_vl_ign(raw) } != 0,
+        })
+    }
+
+    /// The embedded rounding mode, if the instruction uses one.
+    pub fn rounding(&self) -> Option<RoundingMode> {
+        self.rounding
+    }
+
+    /// Whether floating point exceptions are suppressed (`{sae}`).
+    ///
+    /// This is also true when an embedded [`rounding`](Self::rounding) mode is
+    /// used.
+    pub fn sae(&self) -> bool {
+        self.sae
+    }
+
+    /// The number of times the memory element is repeated by an embedded
+    /// broadcast (e.g. `16` for `{1to16}`).
+    pub fn broadcast(&self) -> Option<u32> {
+        self.broadcast
+    }
+
+    /// The mask register, or `None` if the instruction is not masked (i.e.
+    /// uses `k0`).
+    pub fn mask(&self) -> Option<Register> {
+        self.mask
+    }
+
+    /// Whether masked elements are zeroed (`{z}`) instead of merged.
+    pub fn is_zeroing(&self) -> bool {
+        self.zeroing
+    }
+
+    /// Whether masked elements keep their previous value.
+    ///
+    /// This is false for instructions that are not masked.
+    pub fn is_merging(&self) -> bool {
+        self.mask.is_some() && !self.zeroing
+    }
+
+    /// The scaling factor N of a compressed 8-bit displacement (disp8*N).
+    ///
+    /// This is `None` if the instruction has no memory operand.
+    pub fn disp8_scale(&self) -> Option<u32> {
+        self.disp8_scale
+    }
+
+    /// The vector length of the instruction in bits.
+    pub fn vector_length_bits(&self) -> u32 {
+        self.vector_length_bits
+    }
+
+    /// Whether the EVEX.L'L bits are not used as the vector length (`VL_IGN`).
+    ///
+    /// This is the case when they encode an embedded rounding mode or when
+    /// they are ignored by a scalar instruction using `{sae}`.
+    pub fn vector_length_ignored(&self) -> bool {
+        self.vector_length_ignored
+    }
+}
+
+/// The number of copies for the values of XED's `BCAST` operand that
+/// correspond to an embedded `{1toN}` broadcast.
+fn broadcast_factor(bcast: u32) -> Option<u32> {
+    match bcast {
+        11 | 22 | 23 | 26 => Some(2),
+        10 | 13 | 24 | 27 => Some(4),
+        3 | 5 | 14 | 25 => Some(8),
+        1 | 15 | 17 => Some(16),
+        16 | 18 => Some(32),
+        19 => Some(64),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn evex_info(bytes: &[u8]) -> Option<EvexInfo> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        decode(bytes, DecodeOptions::new(state))
+            .unwrap()
+            .evex_info()
+    }
+
+    #[test]
+    fn rounding_and_sae() {
+        // vaddps zmm0{rd-sae}, zmm1, zmm2
+        let evex = evex_info(&[0x62, 0xf1, 0x74, 0x38, 0x58, 0xc2]).unwrap();
+        assert_eq!(evex.rounding(), Some(RoundingMode::Down));
+        assert!(evex.sae());
+        assert!(evex.vector_length_ignored());
+        assert_eq!(evex.vector_length_bits(), 512);
+
+        // vucomiss xmm0{sae}, xmm1
+        let evex = evex_info(&[0x62, 0xf1, 0x7c, 0x18, 0x2e, 0xc1]).unwrap();
+        assert_eq!(evex.rounding(), None);
+        assert!(evex.sae());
+    }
+
+    #[test]
+    fn masking_and_broadcast() {
+        // vaddps zmm0{k1}{z}, zmm0, zmm2
+        let evex = evex_info(&[0x62, 0xf1, 0x7c, 0xc9, 0x58, 0xc2]).unwrap();
+        assert_eq!(evex.mask(), Some(Register::K1));
+        assert!(evex.is_zeroing() && !evex.is_merging());
+        assert_eq!(evex.broadcast(), None);
+        assert_eq!(evex.disp8_scale(), None);
+
+        // vcvtdq2pd zmm0, dword ptr [rax]{1to8}
+        let evex = evex_info(&[0x62, 0xf1, 0x7e, 0x58, 0xe6, 0x00]).unwrap();
+        assert_eq!(evex.mask(), None);
+        assert!(!evex.is_merging());
+        assert_eq!(evex.broadcast(), Some(8));
+        assert!(!evex.vector_length_ignored());
+    }
+
+    #[test]
+    fn disp8_scale() {
+        // vmovups zmm0, zmmword ptr [rax+0x40]
+        let evex = evex_info(&[0x62, 0xf1, 0x7c, 0x48, 0x10, 0x40, 0x01]).unwrap();
+        assert_eq!(evex.disp8_scale(), Some(64));
+
+        // vaddss xmm0, xmm1, dword ptr [rax+0x4]
+        let evex = evex_info(&[0x62, 0xf1, 0x76, 0x08, 0x58, 0x40, 0x01]).unwrap();
+        assert_eq!(evex.disp8_scale(), Some(4));
+        assert_eq!(evex.vector_length_bits(), 128);
+    }
+
+    #[test]
+    fn not_evex() {
+        // vaddps ymm0, ymm1, ymm2
+        assert_eq!(evex_info(&[0xc5, 0xf4, 0x58, 0xc2]), None);
+        // add rax, rbx
+        assert_eq!(evex_info(&[0x48, 0x01, 0xd8]), None);
+        // add eax, eax, ecx
+        assert_eq!(evex_info(&[0x62, 0xf4, 0x7c, 0x18, 0x01, 0xc8]), None);
+        // add eax, dword ptr [rax+0x1], ecx
+        assert_eq!(evex_info(&[0x62, 0xf4, 0x7c, 0x18, 0x01, 0x48, 0x01]), None);
+    }
+}
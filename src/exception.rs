@@ -25,6 +25,7 @@ crate::macros::xed_enum! {
         APX_EVEX_KEYLOCKER,
         APX_EVEX_KMOV,
         APX_EVEX_PP2,
+        APX_EVEX_RAO_INT,
         APX_EVEX_SHA,
         APX_LEGACY_JMPABS,
         AVX512_E1,
@@ -77,6 +78,7 @@ crate::macros::xed_enum! {
         SSE_TYPE_4M,
         SSE_TYPE_5,
         SSE_TYPE_7,
+        USER_MSR_EVEX,
         USER_MSR_LEGACY,
         USER_MSR_VEX,
     }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use xed_sys::*;
 
 crate::macros::xed_enum! {
@@ -32,6 +34,9 @@ crate::macros::xed_enum! {
         /// Interrupt flag
         IF => XED_FLAG_if,
 
+        /// Alignment check
+        AC => XED_FLAG_ac,
+
         /// Virtual-8086 mode
         VM => XED_FLAG_vm,
 
@@ -63,3 +68,23 @@ crate::macros::xed_enum! {
         FC3 => XED_FLAG_fc3
     }
 }
+
+/// Formats the flag using its lowercase assembly name (e.g. `zf`).
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{self:?}").to_ascii_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        assert_eq!(Flag::count(), Flag::VARIANTS.len());
+        assert_eq!(Flag::iter().next(), Some(Flag::OF));
+        assert!(Flag::iter().any(|flag| flag == Flag::FC3));
+        assert_eq!(Flag::ZF.to_string(), "zf");
+    }
+}
@@ -0,0 +1,168 @@
+use super::{DecodedInst, FlagSet};
+
+/// Liveness of the flags around a single instruction.
+///
+/// A flag is live at a point if its current value may be read by some later
+/// instruction before it is overwritten. See [`flag_liveness`] for how this is
+/// computed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FlagLiveness {
+    live_in: FlagSet,
+    live_out: FlagSet,
+    dead_written: FlagSet,
+}
+
+impl FlagLiveness {
+    /// The flags that are live immediately before the instruction executes.
+    pub fn live_in(&self) -> FlagSet {
+        self.live_in
+    }
+
+    /// The flags that are live immediately after the instruction executes.
+    pub fn live_out(&self) -> FlagSet {
+        self.live_out
+    }
+
+    /// The flags that the instruction writes (or leaves undefined) but which
+    /// are not read before they are overwritten again.
+    pub fn dead_written(&self) -> FlagSet {
+        self.dead_written
+    }
+}
+
+/// How a single instruction interacts with the flags.
+struct FlagEffect {
+    /// Flags whose incoming values may be read.
+    read: FlagSet,
+
+    /// Flags that may be written or left undefined.
+    written: FlagSet,
+
+    /// Flags that are always overwritten, so their incoming values are dead.
+    killed: FlagSet,
+}
+
+impl FlagEffect {
+    fn of(inst: &DecodedInst) -> Self {
+        let none = Self {
+            read: FlagSet::new(),
+            written: FlagSet::new(),
+            killed: FlagSet::new(),
+        };
+
+        // Shifts and rotates by an immediate that is masked down to zero have
+        // flag information attached but do not actually touch the flags.
+        if !inst.uses_rflags() {
+            return none;
+        }
+
+        let info = match inst.rflags_info() {
+            Some(info) => info,
+            None => return none,
+        };
+
+        let written = info.written_flag_set().union(info.undefined_flag_set());
+
+        // Conditional writes (e.g. those dependent on a REP count or on a
+        // shift count in CL) may leave the incoming values untouched so they
+        // do not end the liveness of those flags.
+        let killed = if info.must_write() {
+            written
+        } else {
+            FlagSet::new()
+        };
+
+        Self {
+            read: *info.read_flag_set(),
+            written,
+            killed,
+        }
+    }
+}
+
+/// Compute the liveness of the flags across a straight-line sequence of
+/// instructions.
+///
+/// `live_out` is the set of flags that are live after the last instruction in
+/// `insts` has executed. If nothing is known about the code that follows then
+/// [`FlagSet::all`] is the conservative choice.
+///
+/// The returned vector has one entry per instruction in `insts`. Instructions
+/// that only conditionally write flags (see [`SimpleFlag::may_write`]) are
+/// treated as preserving the incoming values of those flags while flags that
+/// are left undefined are treated as being written.
+///
+/// [`SimpleFlag::may_write`]: crate::SimpleFlag::may_write
+pub fn flag_liveness(insts: &[DecodedInst], live_out: FlagSet) -> Vec<FlagLiveness> {
+    let mut result = Vec::with_capacity(insts.len());
+    let mut live = live_out;
+
+    for inst in insts.iter().rev() {
+        let effect = FlagEffect::of(inst);
+        let live_in = live.difference(&effect.killed).union(&effect.read);
+
+        result.push(FlagLiveness {
+            live_in,
+            live_out: live,
+            dead_written: effect.written.difference(&live),
+        });
+
+        live = live_in;
+    }
+
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, Flag, MachineMode, State};
+
+    fn decode_all(bytes: &[&'static [u8]]) -> Vec<DecodedInst<'static>> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        bytes
+            .iter()
+            .map(|bytes| decode(bytes, DecodeOptions::new(state)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn cmp_then_jcc() {
+        let insts = decode_all(&[
+            // add rax, rbx
+            &[0x48, 0x01, 0xd8],
+            // cmp rax, rcx
+            &[0x48, 0x39, 0xc8],
+            // jz +0
+            &[0x74, 0x00],
+        ]);
+
+        let liveness = flag_liveness(&insts, FlagSet::new());
+
+        assert!(liveness[0].live_out().is_empty());
+        assert!(liveness[0].dead_written().contains(Flag::ZF));
+        assert!(liveness[1].live_out().contains(Flag::ZF));
+        assert!(!liveness[1].live_in().contains(Flag::ZF));
+        assert!(liveness[1].dead_written().contains(Flag::CF));
+        assert!(!liveness[1].dead_written().contains(Flag::ZF));
+        assert_eq!(liveness[2].live_in(), FlagSet::from_flags(&[Flag::ZF]));
+    }
+
+    #[test]
+    fn conditional_writes_preserve_liveness() {
+        let insts = decode_all(&[
+            // shl rax, cl
+            &[0x48, 0xd3, 0xe0],
+            // shl rax, 0
+            &[0x48, 0xc1, 0xe0, 0x00],
+        ]);
+
+        let liveness = flag_liveness(&insts, FlagSet::from_flags(&[Flag::CF]));
+
+        assert!(liveness[1].live_in().contains(Flag::CF));
+        assert!(liveness[1].dead_written().is_empty());
+        assert!(liveness[0].live_in().contains(Flag::CF));
+    }
+}
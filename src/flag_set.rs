@@ -1,10 +1,62 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+
 use xed_sys::*;
 
+use super::Flag;
+
+/// The bits within [`xed_flag_set_t`] that correspond to each [`Flag`].
+///
+/// The flag set uses the same layout as the EFLAGS register, with the x87
+/// condition codes stored in the upper bits that are otherwise reserved. This
+/// table is in the same order as the [`Flag`] enum so iteration yields flags in
+/// the same order that XED prints them in.
+const FLAG_BITS: [(Flag, u32); 21] = [
+    (Flag::OF, 1 << 11),
+    (Flag::SF, 1 << 7),
+    (Flag::ZF, 1 << 6),
+    (Flag::AF, 1 << 4),
+    (Flag::PF, 1 << 2),
+    (Flag::CF, 1 << 0),
+    (Flag::DF, 1 << 10),
+    (Flag::VIF, 1 << 19),
+    (Flag::IOPL, 0b11 << 12),
+    (Flag::IF, 1 << 9),
+    (Flag::AC, 1 << 18),
+    (Flag::VM, 1 << 17),
+    (Flag::RF, 1 << 16),
+    (Flag::NT, 1 << 14),
+    (Flag::TF, 1 << 8),
+    (Flag::ID, 1 << 21),
+    (Flag::VIP, 1 << 20),
+    (Flag::FC0, 1 << 28),
+    (Flag::FC1, 1 << 29),
+    (Flag::FC2, 1 << 30),
+    (Flag::FC3, 1 << 31),
+];
+
+fn flag_mask(flag: Flag) -> u32 {
+    FLAG_BITS
+        .iter()
+        .find(|(f, _)| *f == flag)
+        .map(|&(_, mask)| mask)
+        .expect("flag missing from the flag set bit table")
+}
+
+/// A set of [`Flag`]s.
+///
+/// This is what XED uses to describe the flags that are read, written, or left
+/// undefined by an instruction (see [`SimpleFlag`]).
+///
+/// [`SimpleFlag`]: crate::SimpleFlag
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct FlagSet(xed_flag_set_t);
 
 impl FlagSet {
     pub fn from_ref(raw: &xed_flag_set_t) -> &Self {
-        // SAFETY: SimpleFlag is #[repr(transparent)]
+        // SAFETY: FlagSet is #[repr(transparent)]
         unsafe { std::mem::transmute(raw) }
     }
 
@@ -23,6 +75,29 @@ impl FlagSet {
     pub fn as_raw_mut(&mut self) -> &mut xed_flag_set_t {
         &mut self.0
     }
+}
+
+impl FlagSet {
+    /// Create an empty flag set.
+    pub const fn new() -> Self {
+        Self(xed_flag_set_t { flat: 0 })
+    }
+
+    /// Create a flag set containing every [`Flag`].
+    pub fn all() -> Self {
+        FLAG_BITS.iter().map(|&(flag, _)| flag).collect()
+    }
+
+    /// Create a flag set containing exactly the provided flags.
+    pub fn from_flags(flags: &[Flag]) -> Self {
+        flags.iter().copied().collect()
+    }
+
+    /// Create a flag set from a mask in the same layout as returned by
+    /// [`as_mask`](Self::as_mask).
+    pub const fn from_mask(mask: u32) -> Self {
+        Self(xed_flag_set_t { flat: mask })
+    }
 
     /// Get the flags as a mask.
     pub fn as_mask(&self) -> u32 {
@@ -33,4 +108,204 @@ impl FlagSet {
     pub fn is_subset_of(&self, other: &Self) -> bool {
         unsafe { xed_flag_set_is_subset_of(self.as_raw(), other.as_raw()) != 0 }
     }
+
+    /// Whether `flag` is present within this flag set.
+    pub fn contains(&self, flag: Flag) -> bool {
+        self.as_mask() & flag_mask(flag) != 0
+    }
+
+    /// Add `flag` to this flag set.
+    pub fn insert(&mut self, flag: Flag) {
+        *self = Self::from_mask(self.as_mask() | flag_mask(flag));
+    }
+
+    /// Remove `flag` from this flag set.
+    pub fn remove(&mut self, flag: Flag) {
+        *self = Self::from_mask(self.as_mask() & !flag_mask(flag));
+    }
+
+    /// Whether this flag set contains no flags.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of flags present in this flag set.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// The flags that are in either this set or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() | other.as_mask())
+    }
+
+    /// The flags that are in both this set and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() & other.as_mask())
+    }
+
+    /// The flags that are in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_mask(self.as_mask() & !other.as_mask())
+    }
+
+    /// Iterate over the flags present in this flag set.
+    pub fn iter(&self) -> FlagSetIter {
+        FlagSetIter {
+            mask: self.as_mask(),
+            index: 0,
+        }
+    }
+}
+
+impl Default for FlagSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for FlagSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_mask() == other.as_mask()
+    }
+}
+
+impl Eq for FlagSet {}
+
+impl Hash for FlagSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_mask().hash(state)
+    }
+}
+
+impl FromIterator<Flag> for FlagSet {
+    fn from_iter<I: IntoIterator<Item = Flag>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Flag> for FlagSet {
+    fn extend<I: IntoIterator<Item = Flag>>(&mut self, iter: I) {
+        for flag in iter {
+            self.insert(flag);
+        }
+    }
+}
+
+impl IntoIterator for &FlagSet {
+    type Item = Flag;
+    type IntoIter = FlagSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for FlagSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (i, flag) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+
+            f.write_str(&format!("{flag:?}").to_ascii_uppercase())?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+impl fmt::Display for FlagSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+/// Serialized as a sequence of the [`Flag`]s it contains.
+#[cfg(feature = "serde")]
+impl serde::Serialize for FlagSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FlagSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<Flag>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Iterator over the [`Flag`]s within a [`FlagSet`].
+#[derive(Clone)]
+pub struct FlagSetIter {
+    mask: u32,
+    index: usize,
+}
+
+impl Iterator for FlagSetIter {
+    type Item = Flag;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&(flag, mask)) = FLAG_BITS.get(self.index) {
+            self.index += 1;
+
+            if self.mask & mask != 0 {
+                return Some(flag);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for FlagSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_bits_cover_every_flag() {
+        let mut seen = 0u32;
+
+        for (flag, mask) in FLAG_BITS {
+            assert_eq!(seen & mask, 0, "{flag:?} overlaps with another flag");
+            seen |= mask;
+        }
+
+        assert_eq!(FLAG_BITS.len() as u32 + 1, XED_FLAG_LAST);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = FlagSet::from_flags(&[Flag::CF, Flag::ZF, Flag::OF]);
+        let b = FlagSet::from_flags(&[Flag::ZF, Flag::SF]);
+
+        assert!(a.contains(Flag::ZF));
+        assert!(!a.contains(Flag::SF));
+        assert_eq!(a.len(), 3);
+        assert_eq!(
+            a.union(&b),
+            FlagSet::from_flags(&[Flag::CF, Flag::ZF, Flag::OF, Flag::SF])
+        );
+        assert_eq!(a.intersection(&b), FlagSet::from_flags(&[Flag::ZF]));
+        assert_eq!(a.difference(&b), FlagSet::from_flags(&[Flag::CF, Flag::OF]));
+        assert!(a.intersection(&b).is_subset_of(&a));
+    }
+
+    #[test]
+    fn debug_output() {
+        let set = FlagSet::from_flags(&[Flag::CF, Flag::ZF, Flag::OF]);
+
+        assert_eq!(format!("{set:?}"), "{OF,ZF,CF}");
+        assert_eq!(set.to_string(), "{OF,ZF,CF}");
+        assert_eq!(format!("{}", FlagSet::new()), "{}");
+    }
 }
@@ -0,0 +1,348 @@
+use xed_sys::*;
+
+use super::{Dialect, Syntax};
+
+/// Options that control how [`DecodedInst::format`] prints an instruction.
+///
+/// The defaults match the output of [`DecodedInst::disassemble`].
+///
+/// Note that most of these options only apply to the [`Syntax::INTEL`] and
+/// [`Syntax::ATT`] syntaxes. [`Syntax::XED`] output always contains every
+/// operand of the instruction, including implicit and suppressed ones, but
+/// ignores everything here except for the runtime address and the case and
+/// hex prefix options.
+///
+/// Setting a [`Dialect`] rewrites the Intel syntax output of XED into text
+/// that can be assembled by that assembler.
+///
+/// [`DecodedInst::format`]: crate::DecodedInst::format
+/// [`DecodedInst::disassemble`]: crate::DecodedInst::disassemble
+#[derive(Copy, Clone, Debug)]
+pub struct FormatOptions {
+    pub(crate) syntax: Syntax,
+    runtime_address: u64,
+    options: xed_format_options_t,
+    hex_prefix: bool,
+    uppercase: bool,
+    pub(crate) dialect: Option<Dialect>,
+}
+
+impl FormatOptions {
+    /// Create a new set of options for the provided syntax.
+    pub fn new(syntax: Syntax) -> Self {
+        Self {
+            syntax,
+            runtime_address: 0,
+            // These are the defaults that XED uses if no options are set.
+            options: xed_format_options_t {
+                hex_address_before_symbolic_name: 1,
+                xml_a: 0,
+                xml_f: 0,
+                omit_unit_scale: 0,
+                no_sign_extend_signed_immediates: 0,
+                write_mask_curly_k0: 1,
+                lowercase_hex: 1,
+                positive_memory_displacements: 0,
+            },
+            hex_prefix: true,
+            uppercase: false,
+            dialect: None,
+        }
+    }
+
+    /// The syntax that the instruction will be printed in.
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// The assembler dialect to rewrite the output into, or `None` to print
+    /// the output of XED unchanged. Defaults to `None`.
+    ///
+    /// When a dialect is set the instruction is always printed using
+    /// [`Syntax::INTEL`] as a starting point and the
+    /// [`hex_prefix`](Self::hex_prefix) and
+    /// [`address_before_symbol`](Self::address_before_symbol) options are
+    /// ignored.
+    pub fn dialect(mut self, dialect: Option<Dialect>) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// The address at which the instruction is located.
+    ///
+    /// When set, relative branches print their absolute target address instead
+    /// of an address relative to zero.
+    pub fn runtime_address(mut self, address: u64) -> Self {
+        self.runtime_address = address;
+        self
+    }
+
+    /// Whether to print the numeric address before a symbol name that was
+    /// resolved by a [`SymbolResolver`]. Defaults to true.
+    ///
+    /// [`SymbolResolver`]: crate::SymbolResolver
+    pub fn address_before_symbol(mut self, enable: bool) -> Self {
+        self.options.hex_address_before_symbolic_name = enable.into();
+        self
+    }
+
+    /// Whether to sign-extend signed immediates to the effective operand width.
+    /// Defaults to true.
+    ///
+    /// When disabled, signed immediates are printed as unsigned values of the
+    /// same width as their encoding.
+    pub fn sign_extend_immediates(mut self, enable: bool) -> Self {
+        self.options.no_sign_extend_signed_immediates = (!enable).into();
+        self
+    }
+
+    /// Whether to print negative memory displacements as positive numbers.
+    /// Defaults to false.
+    ///
+    /// Displacements are printed at the width that they were encoded with so
+    /// with this enabled an 8-bit displacement of `[rax-0x8]` is printed as
+    /// `[rax+0xf8]`.
+    pub fn positive_memory_displacements(mut self, enable: bool) -> Self {
+        self.options.positive_memory_displacements = enable.into();
+        self
+    }
+
+    /// Whether hexadecimal numbers use lowercase digits. Defaults to true.
+    pub fn lowercase_hex(mut self, enable: bool) -> Self {
+        self.options.lowercase_hex = enable.into();
+        self
+    }
+
+    /// Whether hexadecimal numbers are printed with a leading `0x`. Defaults to
+    /// true.
+    pub fn hex_prefix(mut self, enable: bool) -> Self {
+        self.hex_prefix = enable;
+        self
+    }
+
+    /// Whether mnemonics, registers, and other keywords are printed in
+    /// uppercase. Defaults to false.
+    ///
+    /// Hexadecimal numbers are controlled separately by
+    /// [`lowercase_hex`](Self::lowercase_hex) and symbol names are always
+    /// printed as-is.
+    pub fn uppercase(mut self, enable: bool) -> Self {
+        self.uppercase = enable;
+        self
+    }
+
+    /// Whether to omit the scale in memory operands when it is 1. Defaults to
+    /// false.
+    pub fn omit_unit_scale(mut self, enable: bool) -> Self {
+        self.options.omit_unit_scale = enable.into();
+        self
+    }
+
+    /// Whether to print AVX512 write masks in curly brackets, omitting `k0`.
+    /// Defaults to true.
+    pub fn curly_write_mask(mut self, enable: bool) -> Self {
+        self.options.write_mask_curly_k0 = enable.into();
+        self
+    }
+
+    /// Whether to emit simple XML markup around the parts of the instruction.
+    /// Defaults to false.
+    ///
+    /// This is only supported by the Intel syntax.
+    pub fn xml(mut self, enable: bool) -> Self {
+        self.options.xml_a = enable.into();
+        self
+    }
+
+    /// Whether to include the flags read and written by the instruction in the
+    /// XML output. Defaults to false.
+    ///
+    /// This only has an effect when [`xml`](Self::xml) is also enabled.
+    pub fn xml_flags(mut self, enable: bool) -> Self {
+        self.options.xml_f = enable.into();
+        self
+    }
+}
+
+impl FormatOptions {
+    /// Create the [`xed_print_info_t`] used to format an instruction with
+    /// these options.
+    ///
+    /// The instruction and output buffer still need to be filled in.
+    pub(crate) fn print_info(&self) -> xed_print_info_t {
+        let mut info = new_print_info(self.xed_syntax(), self.runtime_address);
+        info.format_options_valid = 1;
+        info.format_options = self.options;
+        info
+    }
+
+    /// The syntax that XED itself is asked to print.
+    pub(crate) fn xed_syntax(&self) -> Syntax {
+        match self.dialect {
+            Some(_) => Syntax::INTEL,
+            None => self.syntax,
+        }
+    }
+
+    /// Format `value` as a hexadecimal number in the same way as XED.
+    pub(crate) fn hex(&self, value: u64) -> String {
+        if self.options.lowercase_hex != 0 {
+            format!("0x{value:x}")
+        } else {
+            format!("0x{value:X}")
+        }
+    }
+
+    /// Whether the text output by XED needs to be rewritten by
+    /// [`postprocess`](Self::postprocess).
+    pub(crate) fn needs_postprocess(&self) -> bool {
+        self.uppercase || !self.keep_hex_prefix()
+    }
+
+    fn keep_hex_prefix(&self) -> bool {
+        self.hex_prefix || self.dialect.is_some()
+    }
+
+    /// Apply the options that XED doesn't support itself to its output.
+    pub(crate) fn postprocess(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(c) = rest.chars().next() {
+            // Symbols (and XML tags) are always enclosed in angle brackets and
+            // are left untouched.
+            if c == '<' {
+                let end = rest.find('>').map(|end| end + 1).unwrap_or(rest.len());
+                output.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+
+            // Same for XML entities.
+            if c == '&' {
+                let end = rest.find(';').map(|end| end + 1).unwrap_or(rest.len());
+                output.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+
+            if !c.is_ascii_alphanumeric() {
+                output.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            let (word, remainder) = rest.split_at(end);
+            rest = remainder;
+
+            if let Some(digits) = word.strip_prefix("0x") {
+                if self.keep_hex_prefix() {
+                    output.push_str("0x");
+                }
+
+                output.push_str(digits);
+            } else if c.is_ascii_digit() || !self.uppercase {
+                output.push_str(word);
+            } else {
+                output.extend(word.chars().map(|c| c.to_ascii_uppercase()));
+            }
+        }
+
+        output
+    }
+}
+
+/// Create a [`xed_print_info_t`] that uses XED's global formatting options.
+pub(crate) fn new_print_info(syntax: Syntax, runtime_address: u64) -> xed_print_info_t {
+    let mut info = std::mem::MaybeUninit::uninit();
+    unsafe { xed_init_print_info(info.as_mut_ptr()) };
+
+    let mut info = unsafe { info.assume_init() };
+    info.syntax = syntax.into_raw();
+    info.runtime_address = runtime_address;
+    info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn format(bytes: &[u8], options: FormatOptions) -> String {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let inst = decode(bytes, DecodeOptions::new(state)).unwrap();
+
+        inst.format(&options)
+    }
+
+    #[test]
+    fn defaults_match_disassemble() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // mov qword ptr [rbp-0x8], 0xfffffffffffffff0
+        let bytes = [0x48, 0xc7, 0x45, 0xf8, 0xf0, 0xff, 0xff, 0xff];
+        let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+
+        for syntax in [Syntax::INTEL, Syntax::ATT, Syntax::XED] {
+            assert_eq!(
+                inst.format(&FormatOptions::new(syntax)),
+                inst.disassemble(syntax)
+            );
+        }
+    }
+
+    #[test]
+    fn branch_targets() {
+        // jmp +0x10
+        let bytes = [0xeb, 0x10];
+
+        assert_eq!(
+            format(&bytes, FormatOptions::new(Syntax::INTEL)),
+            "jmp 0x12"
+        );
+        assert_eq!(
+            format(
+                &bytes,
+                FormatOptions::new(Syntax::INTEL).runtime_address(0x401000)
+            ),
+            "jmp 0x401012"
+        );
+    }
+
+    #[test]
+    fn immediates_and_case() {
+        // add rax, -0x10
+        let bytes = [0x48, 0x83, 0xc0, 0xf0];
+        let options = FormatOptions::new(Syntax::INTEL);
+
+        assert_eq!(format(&bytes, options), "add rax, 0xfffffffffffffff0");
+        assert_eq!(
+            format(&bytes, options.sign_extend_immediates(false)),
+            "add rax, 0xf0"
+        );
+        assert_eq!(
+            format(&bytes, options.uppercase(true).lowercase_hex(false)),
+            "ADD RAX, 0xFFFFFFFFFFFFFFF0"
+        );
+        assert_eq!(
+            format(&bytes, options.uppercase(true).hex_prefix(false)),
+            "ADD RAX, fffffffffffffff0"
+        );
+    }
+
+    #[test]
+    fn memory_displacements() {
+        // mov rax, qword ptr [rbp-0x8]
+        let bytes = [0x48, 0x8b, 0x45, 0xf8];
+        let options = FormatOptions::new(Syntax::INTEL);
+
+        assert_eq!(format(&bytes, options), "mov rax, qword ptr [rbp-0x8]");
+        assert_eq!(
+            format(&bytes, options.positive_memory_displacements(true)),
+            "mov rax, qword ptr [rbp+0xf8]"
+        );
+    }
+}
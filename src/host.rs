@@ -0,0 +1,191 @@
+//! Detection of the CPU features supported by the host machine.
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86 as arch;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64 as arch;
+
+use std::collections::HashMap;
+
+use super::{Chip, CpuidBit, Register};
+
+/// The results of the `cpuid` and `xgetbv` instructions on the current
+/// machine.
+pub(crate) struct HostCpu {
+    max_basic: u32,
+    max_extended: u32,
+    max_centaur: u32,
+    xcr0: u64,
+    vendor: [u32; 3],
+    leaves: HashMap<(u32, u32), [u32; 4]>,
+}
+
+impl HostCpu {
+    pub(crate) fn new() -> Self {
+        let basic = cpuid(0, 0);
+        let extended = cpuid(0x8000_0000, 0);
+        let centaur = cpuid(0xC000_0000, 0);
+
+        let mut cpu = Self {
+            max_basic: basic.eax,
+            max_extended: extended.eax,
+            max_centaur: centaur.eax,
+            xcr0: 0,
+            vendor: [basic.ebx, basic.edx, basic.ecx],
+            leaves: HashMap::new(),
+        };
+
+        // Query each leaf used by XED once instead of once per bit.
+        for bit in CpuidBit::iter() {
+            let key = (bit.leaf(), bit.subleaf());
+            if cpu.leaves.contains_key(&key) {
+                continue;
+            }
+
+            if let Some(registers) = cpu.query(key.0, key.1) {
+                cpu.leaves.insert(key, registers);
+            }
+        }
+
+        // XGETBV is only available once the OS has enabled XSAVE.
+        if cpu.is_set(CpuidBit::OSXSAVE) {
+            cpu.xcr0 = unsafe { xgetbv() };
+        }
+
+        cpu
+    }
+
+    /// The chip whose ISA sets are assumed to be present when XED has no way
+    /// to detect them using CPUID.
+    pub(crate) fn baseline(&self) -> Chip {
+        match &self.vendor {
+            // "AuthenticAMD"
+            [0x6874_7541, 0x6974_6e65, 0x444d_4163] => Chip::AMD_FUTURE,
+            _ => Chip::FUTURE,
+        }
+    }
+
+    fn query(&self, leaf: u32, subleaf: u32) -> Option<[u32; 4]> {
+        let max = match leaf {
+            0x0000_0000..=0x7FFF_FFFF => self.max_basic,
+            0x8000_0000..=0xBFFF_FFFF => self.max_extended,
+            _ => self.max_centaur,
+        };
+
+        // Querying a leaf above the maximum returns the data for some other
+        // leaf, not zero.
+        if leaf > max || (max & 0xF000_0000) != (leaf & 0xF000_0000) {
+            return None;
+        }
+
+        let result = cpuid(leaf, subleaf);
+        Some([result.eax, result.ebx, result.ecx, result.edx])
+    }
+
+    /// Whether `bit` is set and the OS has enabled any register state that
+    /// the feature needs.
+    pub(crate) fn is_set(&self, bit: CpuidBit) -> bool {
+        let Some(registers) = self.leaves.get(&(bit.leaf(), bit.subleaf())) else {
+            return false;
+        };
+
+        let value = match bit.register() {
+            Register::EAX => registers[0],
+            Register::EBX => registers[1],
+            Register::ECX => registers[2],
+            Register::EDX => registers[3],
+            _ => return false,
+        };
+
+        let state = required_xcr0(bit);
+        bit.is_set(value) && self.xcr0 & state == state
+    }
+}
+
+/// The XCR0 state components that must be enabled by the OS before the
+/// instructions behind `bit` can be used.
+fn required_xcr0(bit: CpuidBit) -> u64 {
+    const SSE_AVX: u64 = 0b110;
+    const MPX: u64 = 0b1_1000;
+    const AVX512: u64 = 0b1110_0000 | SSE_AVX;
+    const AMX: u64 = 0b11 << 17;
+    const APX: u64 = 1 << 19;
+
+    match bit {
+        CpuidBit::AVX512F
+        | CpuidBit::AVX512BW
+        | CpuidBit::AVX512CD
+        | CpuidBit::AVX512DQ
+        | CpuidBit::AVX512ER
+        | CpuidBit::AVX512IFMA
+        | CpuidBit::AVX512PF
+        | CpuidBit::AVX512VBMI
+        | CpuidBit::AVX512VL
+        | CpuidBit::AVX512_4FMAPS
+        | CpuidBit::AVX512_4VNNIW
+        | CpuidBit::AVX512_BITALG
+        | CpuidBit::AVX512_FP16
+        | CpuidBit::AVX512_VBMI2
+        | CpuidBit::AVX512_VNNI
+        | CpuidBit::AVX512_VP2INTERSECT
+        | CpuidBit::AVX512_VPOPCNTDQ
+        | CpuidBit::AVX10_128VL
+        | CpuidBit::AVX10_256VL
+        | CpuidBit::AVX10_512VL
+        | CpuidBit::AVX10_ENABLED
+        | CpuidBit::AVX10_VER1
+        | CpuidBit::BF16 => AVX512,
+        CpuidBit::AVX
+        | CpuidBit::AVX2
+        | CpuidBit::AVX_IFMA
+        | CpuidBit::AVX_NE_CONVERT
+        | CpuidBit::AVX_VNNI
+        | CpuidBit::AVX_VNNI_INT16
+        | CpuidBit::AVX_VNNI_INT8
+        | CpuidBit::FMA
+        | CpuidBit::F16C
+        | CpuidBit::SHA512
+        | CpuidBit::SM3
+        | CpuidBit::SM4
+        | CpuidBit::VAES
+        | CpuidBit::VPCLMULQDQ => SSE_AVX,
+        CpuidBit::AMX_BF16
+        | CpuidBit::AMX_COMPLEX
+        | CpuidBit::AMX_FP16
+        | CpuidBit::AMX_INT8
+        | CpuidBit::AMX_TILES => AMX,
+        CpuidBit::APX_F => APX,
+        CpuidBit::MPX => MPX,
+        _ => 0,
+    }
+}
+
+// CPUID is always available on the targets this module is built for. Newer
+// compilers no longer require an unsafe block to call it.
+#[allow(unused_unsafe)]
+fn cpuid(leaf: u32, subleaf: u32) -> arch::CpuidResult {
+    unsafe { arch::__cpuid_count(leaf, subleaf) }
+}
+
+#[target_feature(enable = "xsave")]
+unsafe fn xgetbv() -> u64 {
+    arch::_xgetbv(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xcr0_state() {
+        assert_eq!(required_xcr0(CpuidBit::AVX2), 0b110);
+        assert_eq!(required_xcr0(CpuidBit::AVX512_VNNI), 0b1110_0110);
+        assert_eq!(required_xcr0(CpuidBit::AVX10_VER1), 0b1110_0110);
+        assert_eq!(required_xcr0(CpuidBit::BF16), 0b1110_0110);
+        assert_eq!(required_xcr0(CpuidBit::VAES), 0b110);
+        assert_eq!(required_xcr0(CpuidBit::VPCLMULQDQ), 0b110);
+        assert_eq!(required_xcr0(CpuidBit::SM3), 0b110);
+        assert_eq!(required_xcr0(CpuidBit::AMX_TILES), 0b11 << 17);
+        assert_eq!(required_xcr0(CpuidBit::SSE2), 0);
+    }
+}
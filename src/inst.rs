@@ -1,9 +1,9 @@
 use xed_sys::*;
 
 use super::{
-    Attribute, Category, DecodedInst, Exception, Extension, IClass, IForm, IsaSet, NonTerminal,
-    Operand, OperandAction, OperandElementXType, OperandType, OperandValue, OperandVisibility,
-    OperandWidth, Register,
+    Attribute, AttributeSet, Category, DecodedInst, Exception, Extension, IClass, IForm, IsaSet,
+    NonTerminal, Operand, OperandAction, OperandElementXType, OperandType, OperandValue,
+    OperandVisibility, OperandWidth, Register,
 };
 
 used_in_docs!(DecodedInst, OperandValue);
@@ -44,19 +44,39 @@ impl Inst {
         unsafe { xed_inst_cpl(self.as_raw()) }
     }
 
-    pub fn operands(&self) -> &[InstOperand] {
-        let len = unsafe { xed_inst_noperands(self.as_raw()) as usize };
-        let ptr = unsafe { xed_inst_operand(self.as_raw(), 0) };
+    /// The number of operands in this instruction template.
+    pub fn noperands(&self) -> usize {
+        unsafe { xed_inst_noperands(self.as_raw()) as usize }
+    }
+
+    /// Get the operand at `index`.
+    pub fn operand(&self, index: usize) -> Option<&InstOperand> {
+        if index >= self.noperands() {
+            return None;
+        }
 
-        unsafe { std::slice::from_raw_parts(ptr as *const InstOperand, len) }
+        let ptr = unsafe { xed_inst_operand(self.as_raw(), index as _) };
+        Some(InstOperand::from_ref(unsafe { &*ptr }))
+    }
+
+    /// Iterate over the operands of this instruction template.
+    ///
+    /// XED shares operands between templates so they are not stored
+    /// contiguously and have to be looked up one by one.
+    pub fn operands(&self) -> impl ExactSizeIterator<Item = &InstOperand> + '_ {
+        (0..self.noperands()).map(|index| self.operand(index).unwrap())
     }
 
     pub fn iform(&self) -> IForm {
-        unsafe { xed_inst_iform_enum(self.as_raw()) }.into()
+        unsafe { xed_inst_iform_enum(self.as_raw()) }
+            .try_into()
+            .expect("instruction iform was invalid")
     }
 
     pub fn iclass(&self) -> IClass {
-        unsafe { xed_inst_iclass(self.as_raw()) }.into()
+        unsafe { xed_inst_iclass(self.as_raw()) }
+            .try_into()
+            .expect("instruction iclass was invalid")
     }
 
     pub fn category(&self) -> Category {
@@ -85,8 +105,8 @@ impl Inst {
         unsafe { xed_inst_get_attribute(self.as_raw(), attr.into_raw()) != 0 }
     }
 
-    pub fn attributes(&self) -> xed_attributes_t {
-        unsafe { xed_inst_get_attributes(self.as_raw()) }
+    pub fn attributes(&self) -> AttributeSet {
+        AttributeSet::from_raw(unsafe { xed_inst_get_attributes(self.as_raw()) })
     }
 }
 
@@ -126,6 +146,14 @@ impl InstOperand {
 }
 
 impl InstOperand {
+    /// Whether this is a memory operand.
+    ///
+    /// This does not include the address computed by `lea` (see
+    /// [`Operand::AGEN`]).
+    pub fn is_memory(&self) -> bool {
+        matches!(self.name(), Operand::MEM0 | Operand::MEM1)
+    }
+
     pub fn name(&self) -> Operand {
         unsafe { xed_operand_name(self.as_raw()) }
             .try_into()
@@ -171,10 +199,13 @@ impl InstOperand {
         unsafe { xed_operand_width_bits(self.as_raw(), eosz) }
     }
 
-    pub fn nonterminal_name(&self) -> NonTerminal {
+    /// The nonterminal used to look up the value of this operand, or `None` if
+    /// the operand doesn't use one (e.g. memory operands, immediates, and
+    /// hard-coded registers).
+    pub fn nonterminal_name(&self) -> Option<NonTerminal> {
         unsafe { xed_operand_nonterminal_name(self.as_raw()) }
             .try_into()
-            .expect("operand nonterminal was invalid")
+            .ok()
     }
 
     /// The implicit or suppressed register.
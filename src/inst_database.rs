@@ -0,0 +1,222 @@
+use xed_sys::*;
+
+use super::{Attribute, Category, Exception, Extension, IClass, IForm, Inst, InstOperand, IsaSet};
+
+/// The table of every instruction template known to XED.
+///
+/// Each template is an [`Inst`], the same type that is returned for a decoded
+/// instruction by [`DecodedInst::inst`], so this can be used to find out about
+/// instructions without having to encode or decode them first.
+///
+/// [`DecodedInst::inst`]: crate::DecodedInst::inst
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InstDatabase(());
+
+impl InstDatabase {
+    /// Get the instruction database.
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    pub fn new() -> Self {
+        super::init_tables();
+
+        Self(())
+    }
+
+    /// Every entry in XED's instruction table, including the invalid
+    /// placeholder entries.
+    fn table(&self) -> &'static [Inst] {
+        let base = unsafe { xed_inst_table_base() };
+        let len = XED_MAX_INST_TABLE_NODES as usize;
+
+        // SAFETY: Inst is #[repr(transparent)] and the table is a static array
+        //         that lives for the rest of the program.
+        unsafe { std::slice::from_raw_parts(base as *const Inst, len) }
+    }
+
+    /// Iterate over every instruction template.
+    pub fn iter(&self) -> impl Iterator<Item = &'static Inst> {
+        self.table()
+            .iter()
+            .filter(|inst| unsafe { xed_inst_iclass(inst.as_raw()) } != XED_ICLASS_INVALID)
+    }
+
+    /// Start a query that only matches some of the instruction templates.
+    pub fn query<'a>(&self) -> InstQuery<'a> {
+        InstQuery::new(*self)
+    }
+}
+
+type InstFilter<'a> = Box<dyn Fn(&Inst) -> bool + 'a>;
+
+/// A filtered view of the instruction templates in an [`InstDatabase`].
+///
+/// Every filter added to the query must match for a template to be returned.
+///
+/// # Example
+/// Find every instruction that writes to memory and reads a ZMM register.
+/// ```
+/// # use xed::*;
+/// let is_zmm = |op: &InstOperand| match op.nonterminal_name() {
+///     Some(nt) => format!("{nt:?}").starts_with("ZMM"),
+///     None => false,
+/// };
+///
+/// let insts: Vec<_> = InstDatabase::new()
+///     .query()
+///     .has_operand(|op| op.is_memory() && op.written())
+///     .has_operand(|op| op.read() && is_zmm(op))
+///     .iter()
+///     .collect();
+///
+/// assert!(insts.iter().any(|inst| inst.iclass() == IClass::VMOVDQU32));
+/// ```
+pub struct InstQuery<'a> {
+    database: InstDatabase,
+    filters: Vec<InstFilter<'a>>,
+}
+
+impl<'a> InstQuery<'a> {
+    fn new(database: InstDatabase) -> Self {
+        Self {
+            database,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Only match templates for which `filter` returns true.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Inst) -> bool + 'a,
+    {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Only match templates with the provided [`IClass`].
+    pub fn iclass(self, iclass: IClass) -> Self {
+        self.filter(move |inst| inst.iclass() == iclass)
+    }
+
+    /// Only match templates with the provided [`IForm`].
+    pub fn iform(self, iform: IForm) -> Self {
+        self.filter(move |inst| inst.iform() == iform)
+    }
+
+    /// Only match templates that belong to the provided [`IsaSet`].
+    pub fn isa_set(self, isa_set: IsaSet) -> Self {
+        self.filter(move |inst| inst.isa_set() == isa_set)
+    }
+
+    /// Only match templates that belong to the provided [`Extension`].
+    pub fn extension(self, extension: Extension) -> Self {
+        self.filter(move |inst| inst.extension() == extension)
+    }
+
+    /// Only match templates that belong to the provided [`Category`].
+    pub fn category(self, category: Category) -> Self {
+        self.filter(move |inst| inst.category() == category)
+    }
+
+    /// Only match templates that have the provided [`Attribute`].
+    pub fn attribute(self, attr: Attribute) -> Self {
+        self.filter(move |inst| inst.attribute(attr))
+    }
+
+    /// Only match templates with the provided [`Exception`] class.
+    pub fn exception(self, exception: Exception) -> Self {
+        self.filter(move |inst| inst.exception() == Some(exception))
+    }
+
+    /// Only match templates that have at least one operand for which
+    /// `filter` returns true.
+    pub fn has_operand<F>(self, filter: F) -> Self
+    where
+        F: Fn(&InstOperand) -> bool + 'a,
+    {
+        self.filter(move |inst| inst.operands().any(&filter))
+    }
+
+    /// Whether `inst` matches every filter in this query.
+    pub fn matches(&self, inst: &Inst) -> bool {
+        self.filters.iter().all(|filter| filter(inst))
+    }
+
+    /// Iterate over the matching instruction templates.
+    pub fn iter(&self) -> impl Iterator<Item = &'static Inst> + '_ {
+        self.database.iter().filter(|inst| self.matches(inst))
+    }
+
+    /// Count the matching instruction templates.
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_template_is_valid() {
+        let database = InstDatabase::new();
+
+        for inst in database.iter() {
+            // These all panic if the template contains an invalid value.
+            inst.iclass();
+            inst.iform();
+            inst.isa_set();
+            inst.extension();
+            inst.category();
+        }
+
+        assert!(database.iter().count() > 1000);
+    }
+
+    #[test]
+    fn template_operands() {
+        use crate::{decode, AddressWidth, DecodeOptions, MachineMode, Operand, State};
+
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // vmovdqu32 zmm0{k1}, zmm1
+        let bytes = [0x62, 0xf1, 0x7e, 0x49, 0x6f, 0xc1];
+        let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+        let names: Vec<_> = inst.inst().operands().map(|op| op.name()).collect();
+
+        assert_eq!(names, [Operand::REG0, Operand::REG1, Operand::REG2]);
+    }
+
+    #[test]
+    fn filters() {
+        let database = InstDatabase::new();
+
+        let adds: Vec<_> = database.query().iclass(IClass::ADD).iter().collect();
+        assert!(!adds.is_empty());
+        assert!(adds.iter().any(|inst| inst.iform() == IForm::ADD_GPRv_IMMb));
+
+        let count = database
+            .query()
+            .iclass(IClass::ADD)
+            .iform(IForm::ADD_GPRv_IMMb)
+            .count();
+        assert!(count >= 1 && count < adds.len());
+
+        assert_eq!(
+            database
+                .query()
+                .extension(Extension::AVX512EVEX)
+                .category(Category::BINARY)
+                .iclass(IClass::ADD)
+                .count(),
+            0
+        );
+
+        let locked = database
+            .query()
+            .attribute(Attribute::LOCKED)
+            .has_operand(|op| op.is_memory() && op.written());
+        assert!(locked.count() > 0);
+        assert!(locked.iter().all(|inst| inst.attribute(Attribute::LOCKED)));
+    }
+}
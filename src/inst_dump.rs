@@ -0,0 +1,700 @@
+use xed_sys::*;
+
+use super::{
+    AttributeSet, Category, DecodedInst, Extension, FlagSet, IClass, IForm, IsaSet, NonTerminal,
+    Operand, OperandAction, OperandElementType, OperandElementXType, OperandType,
+    OperandVisibility, OperandWidth, Register,
+};
+
+/// A complete, owned description of a [`DecodedInst`].
+///
+/// This is the equivalent of XED's `xed_decoded_inst_dump`, but structured so
+/// that it can be inspected programmatically. Use `{:#?}` to get a human
+/// readable dump or, with the `serde` feature, `to_json` to get a machine
+/// readable one.
+///
+/// # Example
+/// ```
+/// # use xed::*;
+/// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+/// // add dword ptr [rax+rbx*4+0x10], 0x7
+/// let bytes = [0x83, 0x44, 0x98, 0x10, 0x07];
+/// let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+///
+/// let dump = InstDump::new(&inst);
+/// assert_eq!(dump.iclass(), IClass::ADD);
+/// assert_eq!(dump.memory_operands()[0].index(), Some(Register::RBX));
+/// assert_eq!(dump.immediate().unwrap().value(), 7);
+/// assert_eq!(dump.positions().modrm(), Some(1));
+///
+/// println!("{dump:#?}");
+/// # #[cfg(feature = "serde")]
+/// println!("{}", dump.to_json());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstDump {
+    bytes: Vec<u8>,
+    iclass: IClass,
+    iform: IForm,
+    category: Category,
+    extension: Extension,
+    isa_set: IsaSet,
+    attributes: AttributeSet,
+    machine_mode_bits: u32,
+    operand_width_bits: u32,
+    operands: Vec<OperandDump>,
+    memory_operands: Vec<MemoryOperandDump>,
+    immediate: Option<ImmediateDump>,
+    second_immediate: Option<u8>,
+    branch_displacement: Option<i64>,
+    flags: Option<FlagsDump>,
+    prefixes: PrefixDump,
+    positions: EncodingPositions,
+}
+
+impl InstDump {
+    /// Dump every field of `inst`.
+    pub fn new(inst: &DecodedInst) -> Self {
+        let raw = inst.as_raw();
+        let values = inst.operands();
+        let operands = inst
+            .inst()
+            .operands()
+            .enumerate()
+            .map(|(index, template)| {
+                let value = values.get(index).expect("operand index out of range");
+
+                OperandDump {
+                    name: template.name(),
+                    visibility: template.visibility(),
+                    ty: template.ty(),
+                    // Operands such as the flags register have no xtype or
+                    // width, which the InstOperand accessors do not allow for.
+                    xtype: unsafe { xed_operand_xtype(template.as_raw()) }
+                        .try_into()
+                        .ok(),
+                    width: unsafe { xed_operand_width(template.as_raw()) }
+                        .try_into()
+                        .ok(),
+                    nonterminal: template.nonterminal_name(),
+                    action: value.action(),
+                    register: inst.reg(template.name()),
+                    length_bits: value.length_bits(),
+                    elements: value.elements(),
+                    element_type: value.element_type(),
+                }
+            })
+            .collect();
+
+        let memory = inst.memory_operands();
+        let memory_operands = (0..memory.len())
+            .filter_map(|index| memory.get(index))
+            .map(|mem| MemoryOperandDump {
+                segment: mem.seg_reg(),
+                base: mem.base_reg(),
+                index: mem.index_reg(),
+                scale: mem.scale(),
+                displacement: mem.displacement(),
+                displacement_width_bits: mem.displacement_width_bits(),
+                length: mem.operand_length(),
+                address_width_bits: mem.address_width(),
+                read: mem.mem_read(),
+                written: mem.mem_written(),
+            })
+            .collect();
+
+        let immediate = (inst.immediate_width() != 0).then(|| ImmediateDump {
+            value: inst.unsigned_immediate(),
+            is_signed: inst.immediate_is_signed(),
+            width_bits: inst.immediate_width_bits(),
+        });
+        let second_immediate = inst
+            .inst()
+            .operands()
+            .any(|op| op.name() == Operand::IMM1)
+            .then(|| inst.second_immediate());
+        let branch_displacement =
+            (inst.branch_displacement_width() != 0).then(|| inst.branch_displacement());
+
+        let flags = inst.rflags_info().map(|info| FlagsDump {
+            read: *info.read_flag_set(),
+            written: *info.written_flag_set(),
+            undefined: *info.undefined_flag_set(),
+            may_write: info.may_write(),
+            must_write: info.must_write(),
+        });
+
+        let prefixes = unsafe {
+            PrefixDump {
+                count: inst.nprefixes(),
+                lock: xed_operand_values_has_lock_prefix(raw) != 0,
+                rep: xed_operand_values_has_rep_prefix(raw) != 0,
+                repne: xed_operand_values_has_repne_prefix(raw) != 0,
+                operand_size: xed_operand_values_has_66_prefix(raw) != 0,
+                address_size: xed_operand_values_has_address_size_prefix(raw) != 0,
+                rex_w: xed_operand_values_has_rexw_prefix(raw) != 0,
+                segment: match xed_operand_values_has_segment_prefix(raw) {
+                    0 => None,
+                    _ => xed_operand_values_segment_prefix(raw).try_into().ok(),
+                },
+            }
+        };
+
+        let positions = unsafe {
+            EncodingPositions {
+                nominal_opcode: xed3_operand_get_pos_nominal_opcode(raw) as u8,
+                modrm: (xed3_operand_get_has_modrm(raw) != 0)
+                    .then(|| xed3_operand_get_pos_modrm(raw) as u8),
+                sib: (xed3_operand_get_has_sib(raw) != 0)
+                    .then(|| xed3_operand_get_pos_sib(raw) as u8),
+                displacement: (xed3_operand_get_disp_width(raw) != 0
+                    || xed3_operand_get_brdisp_width(raw) != 0)
+                    .then(|| xed3_operand_get_pos_disp(raw) as u8),
+                immediate: (xed3_operand_get_imm_width(raw) != 0)
+                    .then(|| xed3_operand_get_pos_imm(raw) as u8),
+                second_immediate: (xed3_operand_get_imm1_bytes(raw) != 0)
+                    .then(|| xed3_operand_get_pos_imm1(raw) as u8),
+            }
+        };
+
+        Self {
+            bytes: inst.bytes().to_vec(),
+            iclass: inst.iclass(),
+            iform: inst.iform(),
+            category: inst.category(),
+            extension: inst.extension(),
+            isa_set: inst.isa_set(),
+            attributes: inst.attributes(),
+            machine_mode_bits: inst.machine_mode_bits(),
+            operand_width_bits: inst.operand_width(),
+            operands,
+            memory_operands,
+            immediate,
+            second_immediate,
+            branch_displacement,
+            flags,
+            prefixes,
+            positions,
+        }
+    }
+
+    /// The bytes that make up the instruction.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The instruction class of the instruction.
+    pub fn iclass(&self) -> IClass {
+        self.iclass
+    }
+
+    /// The iform of the instruction.
+    pub fn iform(&self) -> IForm {
+        self.iform
+    }
+
+    /// The category of the instruction.
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// The extension that the instruction belongs to.
+    pub fn extension(&self) -> Extension {
+        self.extension
+    }
+
+    /// The ISA set that the instruction belongs to.
+    pub fn isa_set(&self) -> IsaSet {
+        self.isa_set
+    }
+
+    /// Every attribute of the instruction.
+    pub fn attributes(&self) -> AttributeSet {
+        self.attributes
+    }
+
+    /// The width of the machine mode in bits (16/32/64).
+    pub fn machine_mode_bits(&self) -> u32 {
+        self.machine_mode_bits
+    }
+
+    /// The effective operand width of the instruction in bits.
+    pub fn operand_width_bits(&self) -> u32 {
+        self.operand_width_bits
+    }
+
+    /// Every operand of the instruction, including suppressed ones.
+    pub fn operands(&self) -> &[OperandDump] {
+        &self.operands
+    }
+
+    /// The memory operands of the instruction.
+    pub fn memory_operands(&self) -> &[MemoryOperandDump] {
+        &self.memory_operands
+    }
+
+    /// The first immediate, if the instruction has one.
+    pub fn immediate(&self) -> Option<ImmediateDump> {
+        self.immediate
+    }
+
+    /// The second immediate, if the instruction has one (e.g. `enter`).
+    pub fn second_immediate(&self) -> Option<u8> {
+        self.second_immediate
+    }
+
+    /// The branch displacement, if the instruction has one.
+    pub fn branch_displacement(&self) -> Option<i64> {
+        self.branch_displacement
+    }
+
+    /// The flags read and written by the instruction, if it uses them.
+    pub fn flags(&self) -> Option<FlagsDump> {
+        self.flags
+    }
+
+    /// The legacy prefixes of the instruction.
+    pub fn prefixes(&self) -> PrefixDump {
+        self.prefixes
+    }
+
+    /// Where each part of the instruction is located within its bytes.
+    pub fn positions(&self) -> EncodingPositions {
+        self.positions
+    }
+
+    /// Render the dump as a JSON object.
+    ///
+    /// Enum values are rendered using their names. This is the same as
+    /// serializing the dump with `serde_json`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("an InstDump can always be serialized")
+    }
+}
+
+impl From<&DecodedInst<'_>> for InstDump {
+    fn from(inst: &DecodedInst<'_>) -> Self {
+        Self::new(inst)
+    }
+}
+
+/// A single operand within an [`InstDump`].
+///
+/// This combines the operand template from the [`Inst`](crate::Inst) with the
+/// values decoded for it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperandDump {
+    name: Operand,
+    visibility: OperandVisibility,
+    ty: OperandType,
+    xtype: Option<OperandElementXType>,
+    width: Option<OperandWidth>,
+    nonterminal: Option<NonTerminal>,
+    action: Option<OperandAction>,
+    register: Option<Register>,
+    length_bits: u32,
+    elements: u32,
+    element_type: Option<OperandElementType>,
+}
+
+impl OperandDump {
+    /// The name of the operand.
+    pub fn name(&self) -> Operand {
+        self.name
+    }
+
+    /// The visibility of the operand.
+    pub fn visibility(&self) -> OperandVisibility {
+        self.visibility
+    }
+
+    /// The [`OperandType`] from the operand template.
+    pub fn ty(&self) -> OperandType {
+        self.ty
+    }
+
+    /// The [`OperandElementXType`] from the operand template.
+    pub fn xtype(&self) -> Option<OperandElementXType> {
+        self.xtype
+    }
+
+    /// The [`OperandWidth`] from the operand template.
+    pub fn width(&self) -> Option<OperandWidth> {
+        self.width
+    }
+
+    /// The nonterminal used to look up the value of the operand.
+    pub fn nonterminal(&self) -> Option<NonTerminal> {
+        self.nonterminal
+    }
+
+    /// The action of the operand, taking masking into account.
+    pub fn action(&self) -> Option<OperandAction> {
+        self.action
+    }
+
+    /// The decoded register, if this is a register operand.
+    pub fn register(&self) -> Option<Register> {
+        self.register
+    }
+
+    /// The decoded length of the operand in bits.
+    pub fn length_bits(&self) -> u32 {
+        self.length_bits
+    }
+
+    /// The number of elements in the operand.
+    pub fn elements(&self) -> u32 {
+        self.elements
+    }
+
+    /// The type of the elements in the operand.
+    pub fn element_type(&self) -> Option<OperandElementType> {
+        self.element_type
+    }
+}
+
+/// A single memory operand within an [`InstDump`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryOperandDump {
+    segment: Option<Register>,
+    base: Option<Register>,
+    index: Option<Register>,
+    scale: u32,
+    displacement: i64,
+    displacement_width_bits: u32,
+    length: u32,
+    address_width_bits: u32,
+    read: bool,
+    written: bool,
+}
+
+impl MemoryOperandDump {
+    /// The segment register.
+    pub fn segment(&self) -> Option<Register> {
+        self.segment
+    }
+
+    /// The base register.
+    pub fn base(&self) -> Option<Register> {
+        self.base
+    }
+
+    /// The index register.
+    pub fn index(&self) -> Option<Register> {
+        self.index
+    }
+
+    /// The scale applied to the index register.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// The displacement.
+    pub fn displacement(&self) -> i64 {
+        self.displacement
+    }
+
+    /// The width of the displacement in bits.
+    pub fn displacement_width_bits(&self) -> u32 {
+        self.displacement_width_bits
+    }
+
+    /// The number of bytes accessed.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    /// The width of the address computation in bits.
+    pub fn address_width_bits(&self) -> u32 {
+        self.address_width_bits
+    }
+
+    /// Whether the memory is read.
+    pub fn read(&self) -> bool {
+        self.read
+    }
+
+    /// Whether the memory is written.
+    pub fn written(&self) -> bool {
+        self.written
+    }
+}
+
+/// The first immediate of an instruction within an [`InstDump`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImmediateDump {
+    value: u64,
+    is_signed: bool,
+    width_bits: u32,
+}
+
+impl ImmediateDump {
+    /// The raw value of the immediate.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Whether the immediate is signed.
+    pub fn is_signed(&self) -> bool {
+        self.is_signed
+    }
+
+    /// The width of the immediate in bits.
+    pub fn width_bits(&self) -> u32 {
+        self.width_bits
+    }
+}
+
+/// The flags used by an instruction within an [`InstDump`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlagsDump {
+    read: FlagSet,
+    written: FlagSet,
+    undefined: FlagSet,
+    may_write: bool,
+    must_write: bool,
+}
+
+impl FlagsDump {
+    /// The flags that are read.
+    pub fn read(&self) -> FlagSet {
+        self.read
+    }
+
+    /// The flags that are written.
+    pub fn written(&self) -> FlagSet {
+        self.written
+    }
+
+    /// The flags that are left undefined.
+    pub fn undefined(&self) -> FlagSet {
+        self.undefined
+    }
+
+    /// Whether the flags are only conditionally written.
+    pub fn may_write(&self) -> bool {
+        self.may_write
+    }
+
+    /// Whether the flags are always written.
+    pub fn must_write(&self) -> bool {
+        self.must_write
+    }
+}
+
+/// The legacy prefixes of an instruction within an [`InstDump`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrefixDump {
+    count: u32,
+    lock: bool,
+    rep: bool,
+    repne: bool,
+    operand_size: bool,
+    address_size: bool,
+    rex_w: bool,
+    segment: Option<Register>,
+}
+
+impl PrefixDump {
+    /// The number of legacy prefixes.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Whether there is a `lock` prefix.
+    pub fn lock(&self) -> bool {
+        self.lock
+    }
+
+    /// Whether there is a `rep`/`repe` prefix.
+    pub fn rep(&self) -> bool {
+        self.rep
+    }
+
+    /// Whether there is a `repne` prefix.
+    pub fn repne(&self) -> bool {
+        self.repne
+    }
+
+    /// Whether there is a `66` prefix.
+    pub fn operand_size(&self) -> bool {
+        self.operand_size
+    }
+
+    /// Whether there is a `67` prefix.
+    pub fn address_size(&self) -> bool {
+        self.address_size
+    }
+
+    /// Whether `REX.W` is set.
+    pub fn rex_w(&self) -> bool {
+        self.rex_w
+    }
+
+    /// The segment override prefix, if there is one.
+    pub fn segment(&self) -> Option<Register> {
+        self.segment
+    }
+}
+
+/// The byte offsets of each part of an instruction within an [`InstDump`].
+///
+/// Parts that are not present in the instruction are `None`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncodingPositions {
+    nominal_opcode: u8,
+    modrm: Option<u8>,
+    sib: Option<u8>,
+    displacement: Option<u8>,
+    immediate: Option<u8>,
+    second_immediate: Option<u8>,
+}
+
+impl EncodingPositions {
+    /// The offset of the nominal opcode byte.
+    pub fn nominal_opcode(&self) -> u8 {
+        self.nominal_opcode
+    }
+
+    /// The offset of the ModRM byte.
+    pub fn modrm(&self) -> Option<u8> {
+        self.modrm
+    }
+
+    /// The offset of the SIB byte.
+    pub fn sib(&self) -> Option<u8> {
+        self.sib
+    }
+
+    /// The offset of the memory or branch displacement.
+    pub fn displacement(&self) -> Option<u8> {
+        self.displacement
+    }
+
+    /// The offset of the first immediate.
+    pub fn immediate(&self) -> Option<u8> {
+        self.immediate
+    }
+
+    /// The offset of the second immediate.
+    pub fn second_immediate(&self) -> Option<u8> {
+        self.second_immediate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, Flag, MachineMode, State};
+
+    fn dump(bytes: &[u8]) -> InstDump {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let inst = decode(bytes, DecodeOptions::new(state)).unwrap();
+
+        InstDump::new(&inst)
+    }
+
+    #[test]
+    fn fields() {
+        // lock add dword ptr fs:[rax+rbx*4+0x10], 0x7
+        let dump = dump(&[0x64, 0xf0, 0x83, 0x44, 0x98, 0x10, 0x07]);
+
+        assert_eq!(dump.iclass(), IClass::ADD_LOCK);
+        assert_eq!(dump.machine_mode_bits(), 64);
+        assert_eq!(dump.operand_width_bits(), 32);
+
+        let prefixes = dump.prefixes();
+        assert_eq!(prefixes.count(), 2);
+        assert!(prefixes.lock());
+        assert!(!prefixes.rep());
+        assert_eq!(prefixes.segment(), Some(Register::FS));
+
+        let positions = dump.positions();
+        assert_eq!(positions.nominal_opcode(), 2);
+        assert_eq!(positions.modrm(), Some(3));
+        assert_eq!(positions.sib(), Some(4));
+        assert_eq!(positions.displacement(), Some(5));
+        assert_eq!(positions.immediate(), Some(6));
+        assert_eq!(positions.second_immediate(), None);
+
+        let mem = dump.memory_operands()[0];
+        assert_eq!(mem.segment(), Some(Register::FS));
+        assert_eq!(mem.base(), Some(Register::RAX));
+        assert_eq!(mem.scale(), 4);
+        assert_eq!(mem.displacement(), 0x10);
+        assert_eq!(mem.length(), 4);
+        assert!(mem.read() && mem.written());
+
+        let immediate = dump.immediate().unwrap();
+        assert_eq!(immediate.value(), 7);
+        assert_eq!(immediate.width_bits(), 8);
+        assert_eq!(dump.branch_displacement(), None);
+
+        let flags = dump.flags().unwrap();
+        assert!(flags.written().contains(Flag::CF));
+        assert!(flags.read().is_empty());
+
+        let names: Vec<_> = dump.operands().iter().map(|op| op.name()).collect();
+        assert_eq!(names, [Operand::MEM0, Operand::IMM0, Operand::REG0]);
+        assert_eq!(dump.operands()[2].register(), Some(Register::RFLAGS));
+        assert_eq!(
+            dump.operands()[2].visibility(),
+            OperandVisibility::SUPPRESSED
+        );
+    }
+
+    #[test]
+    fn branches_and_second_immediate() {
+        // jnz 0x10
+        let dump = self::dump(&[0x75, 0x0e]);
+        assert_eq!(dump.branch_displacement(), Some(0x0e));
+        assert_eq!(dump.positions().displacement(), Some(1));
+        assert_eq!(dump.immediate(), None);
+
+        // enter 0x20, 0x1
+        let dump = self::dump(&[0xc8, 0x20, 0x00, 0x01]);
+        assert_eq!(dump.immediate().unwrap().value(), 0x20);
+        assert_eq!(dump.second_immediate(), Some(1));
+        assert_eq!(dump.positions().second_immediate(), Some(3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        // vaddps zmm0{k1}{z}, zmm1, zmm2
+        let dump = dump(&[0x62, 0xf1, 0x74, 0xc9, 0x58, 0xc2]);
+        let json = dump.to_json();
+
+        assert!(json.starts_with(r#"{"bytes":[98,241,116,201,88,194],"iclass":"VADDPS","#));
+        assert!(json.contains(r#""register":"ZMM0""#));
+        assert!(json.contains(r#""memory_operands":[]"#));
+        assert!(json.contains(r#""flags":null"#));
+
+        let instructions: [&[u8]; 4] = [
+            // lock add dword ptr fs:[rax+rbx*4+0x10], 0x7
+            &[0x64, 0xf0, 0x83, 0x44, 0x98, 0x10, 0x07],
+            // enter 0x20, 0x1
+            &[0xc8, 0x20, 0x00, 0x01],
+            // jnz 0x10
+            &[0x75, 0x0e],
+            // rep movsb
+            &[0xf3, 0xa4],
+        ];
+
+        for bytes in instructions {
+            let dump = self::dump(bytes);
+            let json = dump.to_json();
+
+            assert_eq!(serde_json::from_str::<InstDump>(&json).unwrap(), dump);
+        }
+    }
+}
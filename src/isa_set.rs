@@ -1,5 +1,7 @@
 use xed_sys::*;
 
+use super::{Chip, CpuidBit, CpuidGroup};
+
 crate::macros::xed_enum! {
     pub enum IsaSet => XED_ISA_SET {
         _3DNOW => XED_ISA_SET_3DNOW,
@@ -31,7 +33,9 @@ crate::macros::xed_enum! {
         APX_F_MOVBE,
         APX_F_MOVDIR64B,
         APX_F_MOVDIRI,
+        APX_F_RAO_INT,
         APX_F_SHA,
+        APX_F_USER_MSR,
         APX_F_VMX,
         AVX,
         AVX2,
@@ -228,6 +232,128 @@ crate::macros::xed_enum! {
     }
 }
 
+impl IsaSet {
+    /// Whether this ISA set is supported by `chip`.
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    pub fn is_valid_for_chip(self, chip: Chip) -> bool {
+        super::init_tables();
+
+        unsafe { xed_isa_set_is_valid_for_chip(self.into_raw(), chip.into_raw()) != 0 }
+    }
+
+    /// Iterate over every chip that supports this ISA set.
+    ///
+    /// This includes the [`Chip::ALL`] pseudo-chip.
+    pub fn chips(self) -> impl Iterator<Item = Chip> {
+        Chip::iter().filter(move |&chip| self.is_valid_for_chip(chip))
+    }
+
+    /// The earliest chip in each product line that supports this ISA set.
+    ///
+    /// The product lines are Intel core and server, Intel Atom, Intel Xeon
+    /// Phi, AMD, and VIA. Lines with no chip that supports this ISA set are
+    /// skipped, and the [`Chip::ALL`] and [`Chip::ALLREAL`] pseudo-chips are
+    /// ignored.
+    pub fn first_chip(self) -> Vec<Chip> {
+        Chip::earliest_in_each_line(|chip| self.is_valid_for_chip(chip))
+    }
+
+    /// The alternative groups of CPUID bits that enable this ISA set.
+    ///
+    /// The ISA set is supported if every bit in at least one of the groups is
+    /// set. Most ISA sets have a single group, but some can be enabled in more
+    /// than one way. For example, the AVX-512 ISA sets are also enabled by
+    /// AVX10. ISA sets that cannot be detected using CPUID have no groups.
+    pub fn cpuid_groups(self) -> Vec<CpuidGroup> {
+        (0..XED_MAX_CPUID_GROUPS_PER_ISA_SET)
+            .map(|i| unsafe { xed_get_cpuid_group_enum_for_isa_set(self.into_raw(), i) })
+            .map_while(CpuidGroup::from_raw)
+            .collect()
+    }
+
+    /// The CPUID bits that must all be set for this ISA set to be supported.
+    ///
+    /// When there is more than one way to enable the ISA set (see
+    /// [`cpuid_groups`](Self::cpuid_groups)) this returns the bits of the
+    /// original feature. XED lists newer alternatives, such as AVX10, before
+    /// it.
+    pub fn cpuid_requirements(self) -> Vec<CpuidBit> {
+        self.cpuid_groups()
+            .last()
+            .map(|group| group.bits())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chip_support() {
+        assert!(IsaSet::AVX512F_512.is_valid_for_chip(Chip::SKYLAKE_SERVER));
+        assert!(!IsaSet::AVX512F_512.is_valid_for_chip(Chip::SKYLAKE));
+        assert!(IsaSet::I86.is_valid_for_chip(Chip::ALDER_LAKE));
+
+        assert_eq!(
+            IsaSet::I86.first_chip(),
+            [Chip::I86, Chip::BONNELL, Chip::KNL, Chip::AMD_K10]
+        );
+        assert_eq!(IsaSet::VIA_PADLOCK_AES.first_chip(), [Chip::VIA]);
+        assert_eq!(
+            IsaSet::AVX512F_512.first_chip(),
+            [Chip::SKYLAKE_SERVER, Chip::KNL]
+        );
+        assert_eq!(
+            IsaSet::AVX.first_chip(),
+            [
+                Chip::SANDYBRIDGE,
+                Chip::SIERRA_FOREST,
+                Chip::KNL,
+                Chip::AMD_BULLDOZER
+            ]
+        );
+
+        let chips: Vec<_> = IsaSet::AVX512F_512.chips().collect();
+        assert!(chips.contains(&Chip::ICE_LAKE_SERVER));
+        assert!(!chips.contains(&Chip::HASWELL));
+
+        assert!(Chip::HASWELL
+            .isa_sets()
+            .any(|isa_set| isa_set == IsaSet::AVX2));
+        assert!(Chip::HASWELL
+            .isa_sets()
+            .all(|isa_set| isa_set != IsaSet::AVX512F_512));
+    }
+
+    #[test]
+    fn cpuid_requirements() {
+        assert_eq!(IsaSet::AVX2.cpuid_requirements(), [CpuidBit::AVX2]);
+        assert_eq!(
+            IsaSet::AVX512F_256.cpuid_requirements(),
+            [CpuidBit::AVX512F, CpuidBit::AVX512VL]
+        );
+        assert!(IsaSet::_3DNOW.cpuid_requirements().is_empty());
+
+        let groups = IsaSet::AVX512F_512.cpuid_groups();
+        assert_eq!(
+            groups,
+            [CpuidGroup::AVX512F_512_AVX10, CpuidGroup::AVX512F_512]
+        );
+        assert_eq!(
+            groups[0].bits(),
+            [
+                CpuidBit::AVX10_ENABLED,
+                CpuidBit::AVX10_VER1,
+                CpuidBit::AVX10_512VL
+            ]
+        );
+    }
+}
+
 // c_enum! {
 
 // }
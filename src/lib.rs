@@ -1,4 +1,4 @@
-//!
+//! Safe bindings to the Intel X86 Encoder Decoder (XED) library.
 
 /// Helper macro used to silence `unused_import` warnings when an item is
 /// only imported in order to refer to it within a doc comment.
@@ -19,22 +19,37 @@ mod macros;
 
 mod action;
 mod address_width;
+mod apx_info;
 mod attribute;
+mod attribute_set;
 mod category;
 mod chip;
 mod chip_features;
+mod control_flow;
+mod cpuid_bit;
+mod cpuid_group;
 mod decode;
 mod decoded_inst;
+mod dialect;
 mod error;
+mod evex_info;
 mod exception;
 mod extension;
 mod flag;
+mod flag_liveness;
 mod flag_set;
+mod format;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod host;
 mod iclass;
 mod iform;
 mod inst;
+mod inst_database;
+mod inst_dump;
 mod isa_set;
+mod listing;
 mod machine_mode;
+mod memory_access;
 mod nonterminal;
 mod operand;
 mod operand_action;
@@ -44,29 +59,50 @@ mod operand_visibility;
 mod operand_width;
 mod operand_xtype;
 mod register;
+mod requirements;
 mod simple_flag;
+mod snapshot;
+mod stack_effect;
 mod state;
+mod symbol;
 mod syntax;
+mod token;
 mod util;
 
 pub use self::action::{Action, FlagAction};
 pub use self::address_width::AddressWidth;
+pub use self::apx_info::{ApxEncoding, ApxInfo, ConditionCode};
 pub use self::attribute::Attribute;
+pub use self::attribute_set::{AttributeSet, AttributeSetIter};
 pub use self::category::Category;
 pub use self::chip::Chip;
-pub use self::chip_features::ChipFeatures;
+pub use self::chip_features::{ChipFeatures, ChipFeaturesDiff, ChipFeaturesIter};
+pub use self::control_flow::{BranchTarget, ControlFlow, IndirectTarget};
+pub use self::cpuid_bit::CpuidBit;
+pub use self::cpuid_group::CpuidGroup;
 pub use self::decode::{decode, DecodeOptions};
 pub use self::decoded_inst::*;
+pub use self::dialect::Dialect;
 pub use self::error::*;
+pub use self::evex_info::{EvexInfo, RoundingMode};
 pub use self::exception::Exception;
 pub use self::extension::Extension;
 pub use self::flag::Flag;
-pub use self::flag_set::FlagSet;
+pub use self::flag_liveness::{flag_liveness, FlagLiveness};
+pub use self::flag_set::{FlagSet, FlagSetIter};
+pub use self::format::FormatOptions;
 pub use self::iclass::IClass;
 pub use self::iform::IForm;
 pub use self::inst::{Inst, InstOperand};
+pub use self::inst_database::{InstDatabase, InstQuery};
+pub use self::inst_dump::{
+    EncodingPositions, FlagsDump, ImmediateDump, InstDump, MemoryOperandDump, OperandDump,
+    PrefixDump,
+};
 pub use self::isa_set::IsaSet;
+pub use self::listing::Listing;
 pub use self::machine_mode::MachineMode;
+pub use self::memory_access::{AccessKind, MemoryAccess};
 pub use self::nonterminal::NonTerminal;
 pub use self::operand::Operand;
 pub use self::operand_action::OperandAction;
@@ -76,9 +112,14 @@ pub use self::operand_visibility::OperandVisibility;
 pub use self::operand_width::OperandWidth;
 pub use self::operand_xtype::OperandElementXType;
 pub use self::register::Register;
+pub use self::requirements::{RequiredInst, RequirementsReport, Usage};
 pub use self::simple_flag::SimpleFlag;
+pub use self::snapshot::InstSnapshot;
+pub use self::stack_effect::StackEffect;
 pub use self::state::State;
+pub use self::symbol::SymbolResolver;
 pub use self::syntax::Syntax;
+pub use self::token::{Token, TokenKind};
 
 /// Initialize the XED encode and decode tables.
 ///
@@ -0,0 +1,350 @@
+use std::fmt::{self, Write};
+
+use super::{DecodedInst, FormatOptions, SymbolResolver, Syntax};
+
+/// Renders instructions as an objdump-style listing.
+///
+/// Each instruction is printed on its own line as its address, its encoded
+/// bytes, and its disassembly:
+///
+/// ```text
+/// 00001000: 48 8b 05 fb 0f 00 00  mov rax, qword ptr [rip+0xffb]
+/// ```
+///
+/// Instructions with more bytes than fit on one line continue on the lines
+/// that follow it. Section headers and labels can be inserted either manually
+/// or, when using [`write_all_with`](Self::write_all_with), from the symbols
+/// known to a [`SymbolResolver`].
+#[derive(Copy, Clone, Debug)]
+pub struct Listing {
+    options: FormatOptions,
+    bytes_per_line: usize,
+    address_width: usize,
+    isa_set: bool,
+    iform: bool,
+    flags: bool,
+}
+
+impl Listing {
+    /// Create a new listing that formats instructions using `options`.
+    ///
+    /// The runtime address in `options` is replaced by the address of each
+    /// instruction as it is written.
+    pub fn new(options: FormatOptions) -> Self {
+        Self {
+            options,
+            bytes_per_line: 7,
+            address_width: 8,
+            isa_set: false,
+            iform: false,
+            flags: false,
+        }
+    }
+
+    /// The number of instruction bytes to print on each line. Defaults to 7.
+    pub fn bytes_per_line(mut self, count: usize) -> Self {
+        self.bytes_per_line = count.max(1);
+        self
+    }
+
+    /// The minimum number of hex digits used to print addresses. Defaults to 8.
+    pub fn address_width(mut self, digits: usize) -> Self {
+        self.address_width = digits;
+        self
+    }
+
+    /// Whether to annotate each instruction with its ISA set. Defaults to
+    /// false.
+    pub fn isa_set(mut self, enable: bool) -> Self {
+        self.isa_set = enable;
+        self
+    }
+
+    /// Whether to annotate each instruction with its iform. Defaults to false.
+    pub fn iform(mut self, enable: bool) -> Self {
+        self.iform = enable;
+        self
+    }
+
+    /// Whether to annotate each instruction with the flags it reads and
+    /// writes. Defaults to false.
+    pub fn flags(mut self, enable: bool) -> Self {
+        self.flags = enable;
+        self
+    }
+}
+
+impl Listing {
+    /// Write a header for the start of a section.
+    pub fn write_section<W: Write + ?Sized>(&self, out: &mut W, name: &str) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "Disassembly of section {name}:")
+    }
+
+    /// Write a label for the code at `address`.
+    ///
+    /// The address is printed with the same number of digits as the address of
+    /// each instruction.
+    pub fn write_label<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        address: u64,
+        name: &str,
+    ) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{address:0width$x} <{name}>:",
+            width = self.address_width
+        )
+    }
+
+    /// Write a single instruction located at `address`.
+    pub fn write_inst<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        address: u64,
+        inst: &DecodedInst,
+    ) -> fmt::Result {
+        let text = inst.format(&self.options.runtime_address(address));
+        self.write_line(out, address, inst, &text)
+    }
+
+    /// Write a sequence of instructions that are laid out one after the
+    /// other starting at `address`.
+    pub fn write_all<'a, 'd: 'a, W, I>(&self, out: &mut W, address: u64, insts: I) -> fmt::Result
+    where
+        W: Write + ?Sized,
+        I: IntoIterator<Item = &'a DecodedInst<'d>>,
+    {
+        let mut address = address;
+
+        for inst in insts {
+            self.write_inst(out, address, inst)?;
+            address = address.wrapping_add(inst.length() as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Write a sequence of instructions that are laid out one after the
+    /// other starting at `address`, resolving addresses to symbols using
+    /// `resolver`.
+    ///
+    /// A label is written before each instruction that is at the start of a
+    /// symbol.
+    pub fn write_all_with<'a, 'd: 'a, W, I, R>(
+        &self,
+        out: &mut W,
+        address: u64,
+        insts: I,
+        resolver: &R,
+    ) -> fmt::Result
+    where
+        W: Write + ?Sized,
+        I: IntoIterator<Item = &'a DecodedInst<'d>>,
+        R: SymbolResolver,
+    {
+        let mut address = address;
+
+        for inst in insts {
+            if let Some((name, 0)) = resolver.resolve(address) {
+                self.write_label(out, address, &name)?;
+            }
+
+            let text = inst.format_with(&self.options.runtime_address(address), resolver);
+            self.write_line(out, address, inst, &text)?;
+            address = address.wrapping_add(inst.length() as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Render a sequence of instructions starting at `address` into a string.
+    ///
+    /// See [`write_all`](Self::write_all).
+    pub fn render<'a, 'd: 'a, I>(&self, address: u64, insts: I) -> String
+    where
+        I: IntoIterator<Item = &'a DecodedInst<'d>>,
+    {
+        let mut output = String::new();
+        self.write_all(&mut output, address, insts)
+            .expect("writing to a String cannot fail");
+        output
+    }
+
+    fn write_line<W: Write + ?Sized>(
+        &self,
+        out: &mut W,
+        address: u64,
+        inst: &DecodedInst,
+        text: &str,
+    ) -> fmt::Result {
+        let column = self.bytes_per_line * 3 - 1;
+        let mut chunks = inst.bytes().chunks(self.bytes_per_line);
+        let first = chunks.next().unwrap_or_default();
+
+        self.write_address(out, address)?;
+        // XED leaves a trailing space after instructions without operands.
+        write!(out, "{:column$}  {}", HexBytes(first), text.trim_end())?;
+        self.write_annotations(out, inst)?;
+        writeln!(out)?;
+
+        let mut offset = first.len() as u64;
+        for chunk in chunks {
+            self.write_address(out, address.wrapping_add(offset))?;
+            writeln!(out, "{}", HexBytes(chunk))?;
+            offset += chunk.len() as u64;
+        }
+
+        Ok(())
+    }
+
+    fn write_address<W: Write + ?Sized>(&self, out: &mut W, address: u64) -> fmt::Result {
+        write!(out, "{address:0width$x}: ", width = self.address_width)
+    }
+
+    fn write_annotations<W: Write + ?Sized>(&self, out: &mut W, inst: &DecodedInst) -> fmt::Result {
+        let mut annotations = Vec::new();
+
+        if self.isa_set {
+            annotations.push(format!("isa_set: {:?}", inst.isa_set()));
+        }
+
+        if self.iform {
+            annotations.push(format!("iform: {:?}", inst.iform()));
+        }
+
+        if self.flags {
+            if let Some(info) = inst.rflags_info() {
+                let written = info.written_flag_set().union(info.undefined_flag_set());
+                annotations.push(format!("flags: r={} w={}", info.read_flag_set(), written));
+            }
+        }
+
+        if annotations.is_empty() {
+            return Ok(());
+        }
+
+        let comment = match self.options.xed_syntax() {
+            Syntax::ATT => '#',
+            _ => ';',
+        };
+
+        write!(out, "  {comment} {}", annotations.join(", "))
+    }
+}
+
+/// Space-separated hex bytes, which respects padding.
+struct HexBytes<'a>(&'a [u8]);
+
+impl fmt::Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = String::with_capacity(self.0.len() * 3);
+
+        for (i, byte) in self.0.iter().enumerate() {
+            if i != 0 {
+                text.push(' ');
+            }
+
+            write!(text, "{byte:02x}")?;
+        }
+
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn decode_all(bytes: &[&'static [u8]]) -> Vec<DecodedInst<'static>> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        bytes
+            .iter()
+            .map(|bytes| decode(bytes, DecodeOptions::new(state)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn columns_and_wrapping() {
+        let insts = decode_all(&[
+            // push rbp
+            &[0x55],
+            // mov rax, 0x123456789abcdef
+            &[0x48, 0xb8, 0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01],
+            // jmp 0x1000
+            &[0xeb, 0xf3],
+        ]);
+        let listing = Listing::new(FormatOptions::new(Syntax::INTEL));
+
+        assert_eq!(
+            listing.render(0x1000, &insts),
+            concat!(
+                "00001000: 55                    push rbp\n",
+                "00001001: 48 b8 ef cd ab 89 67  mov rax, 0x123456789abcdef\n",
+                "00001008: 45 23 01\n",
+                "0000100b: eb f3                 jmp 0x1000\n",
+            )
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let insts = decode_all(&[
+            // add rax, rbx
+            &[0x48, 0x01, 0xd8],
+        ]);
+        let listing = Listing::new(FormatOptions::new(Syntax::ATT))
+            .bytes_per_line(4)
+            .address_width(4)
+            .isa_set(true)
+            .iform(true)
+            .flags(true);
+
+        assert_eq!(
+            listing.render(0, &insts),
+            "0000: 48 01 d8     add %rbx, %rax  # isa_set: I86, \
+             iform: ADD_GPRv_GPRv_01, flags: r={} w={OF,SF,ZF,AF,PF,CF}\n"
+        );
+    }
+
+    #[test]
+    fn sections_and_labels() {
+        let insts = decode_all(&[
+            // nop
+            &[0x90],
+            // ret
+            &[0xc3],
+            // call 0x1000
+            &[0xe8, 0xfa, 0xff, 0xff, 0xff],
+        ]);
+        let symbols = BTreeMap::from([(0x1000, "first"), (0x1002, "second")]);
+        let listing = Listing::new(FormatOptions::new(Syntax::INTEL));
+
+        let mut output = String::new();
+        listing.write_section(&mut output, ".text").unwrap();
+        listing
+            .write_all_with(&mut output, 0x1000, &insts, &symbols)
+            .unwrap();
+
+        assert_eq!(
+            output,
+            concat!(
+                "\n",
+                "Disassembly of section .text:\n",
+                "\n",
+                "00001000 <first>:\n",
+                "00001000: 90                    nop\n",
+                "00001001: c3                    ret\n",
+                "\n",
+                "00001002 <second>:\n",
+                "00001002: e8 fa ff ff ff        call 0x1001 <first+0x1>\n",
+            )
+        );
+    }
+}
@@ -20,6 +20,52 @@ macro_rules! wrapper_enum {
             )*
         }
 
+        impl $name {
+            /// Every variant, in declaration order.
+            ///
+            /// This is named `VARIANTS` rather than `ALL` so that it cannot
+            /// collide with a variant, such as [`Chip::ALL`](crate::Chip::ALL).
+            pub const VARIANTS: &'static [Self] = &[ $( Self::$variant, )* ];
+
+            /// Iterate over every variant, in declaration order.
+            pub fn iter() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+                Self::VARIANTS.iter().copied()
+            }
+
+            /// Iterate over every variant. This is an alias of
+            /// [`iter`](Self::iter).
+            pub fn all() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+                Self::iter()
+            }
+
+            /// The number of variants.
+            pub const fn count() -> usize {
+                Self::VARIANTS.len()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(match self {
+                    $( Self::$variant => stringify!($variant), )*
+                })
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const NAMES: &[&str] = &[ $( stringify!($variant), )* ];
+
+                let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                match &*name {
+                    $( stringify!($variant) => Ok(Self::$variant), )*
+                    _ => Err(::serde::de::Error::unknown_variant(&name, NAMES)),
+                }
+            }
+        }
+
         #[automatically_derived]
         impl From<$name> for core::ffi::c_uint {
             fn from(value: $name) -> Self {
@@ -130,6 +176,14 @@ macro_rules! wrapper_enum {
             )*
 
             assert!($name::try_from(core::ffi::c_uint::MAX).is_err());
+            assert!($name::all().eq($name::iter()));
+            assert_eq!($name::count(), $name::VARIANTS.len());
+
+            #[cfg(feature = "serde")]
+            for &variant in $name::VARIANTS {
+                let json = serde_json::to_string(&variant).unwrap();
+                assert_eq!(serde_json::from_str::<$name>(&json).unwrap(), variant);
+            }
         }
     }
 }
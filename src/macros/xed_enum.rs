@@ -10,6 +10,7 @@ macro_rules! xed_enum {
 
         $( name = $enum_name:ident ; )?
         $( invalid = $invalid:ident; )?
+        $( str2 = $str2:path; )?
     } => {
         $( #[$attr] )*
         #[derive(Copy, Clone, Eq, PartialEq, Hash)]
@@ -60,6 +61,33 @@ macro_rules! xed_enum {
             }
         }
 
+        impl $name {
+            /// Every variant, in order of their underlying enum values.
+            ///
+            /// This is named `VARIANTS` rather than `ALL` so that it cannot
+            /// collide with a variant, such as [`Chip::ALL`](crate::Chip::ALL).
+            pub const VARIANTS: &'static [Self] = &[
+                $( Self::$variant, )*
+            ];
+
+            /// Iterate over every variant, in order of their underlying enum
+            /// values.
+            pub fn iter() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+                Self::VARIANTS.iter().copied()
+            }
+
+            /// Iterate over every variant. This is an alias of
+            /// [`iter`](Self::iter).
+            pub fn all() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+                Self::iter()
+            }
+
+            /// The number of variants.
+            pub const fn count() -> usize {
+                Self::VARIANTS.len()
+            }
+        }
+
         impl From<$name> for u32 {
             fn from(value: $name) -> u32 {
                 value.into_raw()
@@ -117,6 +145,7 @@ macro_rules! xed_enum {
 
                 let variant = unsafe {
                     paste::paste!($crate::macros::first!(
+                        $( [ $str2(cstr.as_ptr()) ] )?
                         $( [ xed_sys::[< str2 $enum_name >](cstr.as_ptr()) ] )?
                         [ xed_sys::[< str2 $base:lower _enum_t >](cstr.as_ptr()) ]
                     ))
@@ -132,6 +161,21 @@ macro_rules! xed_enum {
             }
         }
 
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&format_args!("{:?}", self))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                name.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+
         paste::paste! {
             #[test]
             fn [< $name:snake:lower _up_to_date >]() {
@@ -139,6 +183,43 @@ macro_rules! xed_enum {
                     $( $name::$variant, )*
                 ];
 
+                for pair in variants.windows(2) {
+                    assert!(
+                        pair[0].into_raw() < pair[1].into_raw(),
+                        "Enum definition out of order: {:?} is listed before {:?}",
+                        pair[0],
+                        pair[1]
+                    );
+                }
+
+                for &variant in variants {
+                    let name = format!("{:?}", variant);
+                    assert_eq!(
+                        name.parse::<$name>().ok(),
+                        Some(variant),
+                        "Name {:?} does not round-trip",
+                        name
+                    );
+
+                    #[cfg(feature = "serde")]
+                    assert_eq!(
+                        serde_json::from_value::<$name>(serde_json::json!(name)).ok(),
+                        Some(variant),
+                        "Name {:?} does not round-trip through serde",
+                        name
+                    );
+                }
+
+                let invalid = $crate::macros::first!(
+                    $( [ $invalid ] )?
+                    [ [< $base _ INVALID >] ]
+                );
+                assert_eq!(
+                    variants.len() as u32,
+                    [< $base _ LAST >] - invalid - 1,
+                    "Enum definition is missing variants"
+                );
+
                 let max = variants
                     .iter()
                     .copied()
@@ -179,6 +260,7 @@ macro_rules! xed_enum {
 
         $( name = $enum_name:ident; )?
         $( invalid = $invalid:ident; )?
+        $( str2 = $str2:path; )?
     } => {
         paste::paste! {
             $crate::macros::xed_enum! {
@@ -192,6 +274,7 @@ macro_rules! xed_enum {
 
                 $( name = $enum_name; )?
                 $( invalid = $invalid; )?
+                $( str2 = $str2; )?
             }
         }
     }
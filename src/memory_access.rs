@@ -0,0 +1,264 @@
+use super::{Attribute, Category, DecodedInst, Operand, OperandVisibility, Register};
+
+/// Whether a [`MemoryAccess`] reads, writes, or both reads and writes memory.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccessKind {
+    /// Memory is only read.
+    Read,
+
+    /// Memory is only written.
+    Write,
+
+    /// Memory is read and then written (e.g. `add [rax], ebx`).
+    ReadWrite,
+}
+
+impl AccessKind {
+    /// Whether memory is read.
+    pub fn is_read(self) -> bool {
+        matches!(self, Self::Read | Self::ReadWrite)
+    }
+
+    /// Whether memory is written.
+    pub fn is_write(self) -> bool {
+        matches!(self, Self::Write | Self::ReadWrite)
+    }
+}
+
+/// A single memory access made by an instruction.
+///
+/// Returned by [`DecodedInst::memory_accesses`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MemoryAccess {
+    operand: usize,
+    memory_operand: usize,
+    kind: AccessKind,
+    size: u32,
+    element_size_bits: u32,
+    elements: u32,
+    implicit: bool,
+    non_temporal: bool,
+    masked: bool,
+}
+
+impl MemoryAccess {
+    pub(crate) fn collect(inst: &DecodedInst) -> Vec<Self> {
+        // These take a memory operand but never access it. Prefetches only
+        // hint that the memory will be accessed later.
+        if matches!(
+            inst.category(),
+            Category::NOP | Category::WIDENOP | Category::PREFETCH
+        ) {
+            return Vec::new();
+        }
+
+        let memory = inst.memory_operands();
+        let values = inst.operands();
+        let non_temporal = inst.attribute(Attribute::NONTEMPORAL);
+        let masked = inst.masked_vector_operation();
+
+        inst.inst()
+            .operands()
+            .enumerate()
+            .filter_map(|(operand, template)| {
+                let memory_operand = match template.name() {
+                    Operand::MEM0 => 0,
+                    Operand::MEM1 => 1,
+                    _ => return None,
+                };
+                let mem = memory.get(memory_operand)?;
+                let value = values.get(operand)?;
+
+                let kind = match (mem.mem_read(), mem.mem_written()) {
+                    (true, true) => AccessKind::ReadWrite,
+                    (true, false) => AccessKind::Read,
+                    (false, true) => AccessKind::Write,
+                    (false, false) => return None,
+                };
+
+                // XED describes each element of a gather or scatter (VSIB
+                // addressing) as its own access, so count the elements of the
+                // vector register being loaded or stored instead.
+                let elements = match mem.index_reg() {
+                    Some(index) if is_vector(index) => vector_elements(inst),
+                    _ => None,
+                };
+
+                Some(Self {
+                    operand,
+                    memory_operand,
+                    kind,
+                    size: mem.operand_length(),
+                    element_size_bits: value.element_size_bits(),
+                    elements: elements.unwrap_or_else(|| value.elements()),
+                    implicit: template.visibility() != OperandVisibility::EXPLICIT,
+                    non_temporal,
+                    masked,
+                })
+            })
+            .collect()
+    }
+
+    /// The index of the operand within [`DecodedInst::operands`].
+    pub fn operand(&self) -> usize {
+        self.operand
+    }
+
+    /// The index of the operand within [`DecodedInst::memory_operands`].
+    pub fn memory_operand(&self) -> usize {
+        self.memory_operand
+    }
+
+    /// Whether memory is read, written, or both.
+    pub fn kind(&self) -> AccessKind {
+        self.kind
+    }
+
+    /// The number of bytes accessed.
+    ///
+    /// For broadcasts this is the size of the single element that is read.
+    /// For gathers and scatters this is the size of each individual element.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// The size of each element in bits.
+    pub fn element_size_bits(&self) -> u32 {
+        self.element_size_bits
+    }
+
+    /// The number of elements accessed.
+    ///
+    /// For gathers and scatters this is the number of elements in the vector
+    /// register, each of which is a separate access of [`size`](Self::size)
+    /// bytes.
+    pub fn elements(&self) -> u32 {
+        self.elements
+    }
+
+    /// Whether the access is not encoded as an explicit operand (e.g. the
+    /// stack accesses of `push` and `call` or the accesses of `movs`).
+    pub fn is_implicit(&self) -> bool {
+        self.implicit
+    }
+
+    /// Whether the access uses a non-temporal hint.
+    pub fn is_non_temporal(&self) -> bool {
+        self.non_temporal
+    }
+
+    /// Whether the access is masked, so only some elements may be accessed.
+    pub fn is_masked(&self) -> bool {
+        self.masked
+    }
+}
+
+/// The number of elements in the first vector register operand.
+fn vector_elements(inst: &DecodedInst) -> Option<u32> {
+    let values = inst.operands();
+
+    inst.inst()
+        .operands()
+        .enumerate()
+        .filter(|(_, template)| inst.reg(template.name()).is_some_and(is_vector))
+        .find_map(|(operand, _)| values.get(operand))
+        .map(|value| value.elements())
+}
+
+fn is_vector(reg: Register) -> bool {
+    reg.is_xmm() || reg.is_ymm() || reg.is_zmm()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn accesses(bytes: &[u8]) -> Vec<MemoryAccess> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        decode(bytes, DecodeOptions::new(state))
+            .unwrap()
+            .memory_accesses()
+    }
+
+    #[test]
+    fn explicit_accesses() {
+        // lock xadd dword ptr [rax], ecx
+        let access = accesses(&[0xf0, 0x0f, 0xc1, 0x08])[0];
+        assert_eq!(access.kind(), AccessKind::ReadWrite);
+        assert_eq!(access.size(), 4);
+        assert!(!access.is_implicit());
+
+        // movntps xmmword ptr [rax], xmm0
+        let access = accesses(&[0x0f, 0x2b, 0x00])[0];
+        assert_eq!(access.kind(), AccessKind::Write);
+        assert_eq!((access.elements(), access.element_size_bits()), (4, 32));
+        assert!(access.is_non_temporal());
+
+        // vmovups zmmword ptr [rax]{k1}, zmm0
+        let access = accesses(&[0x62, 0xf1, 0x7c, 0x49, 0x11, 0x00])[0];
+        assert_eq!(access.size(), 64);
+        assert!(access.is_masked());
+
+        // vfmadd231ps zmm0{k1}, zmm1, dword ptr [rax+rbx*8+0x12345678]{1to16}
+        let access = accesses(&[
+            0x62, 0xf2, 0x75, 0x59, 0xb8, 0x84, 0xd8, 0x78, 0x56, 0x34, 0x12,
+        ])[0];
+        assert_eq!(access.operand(), 3);
+        assert_eq!(access.kind(), AccessKind::Read);
+        assert_eq!(access.size(), 4);
+    }
+
+    #[test]
+    fn gathers_and_scatters() {
+        // vpgatherdd zmm0{k1}, dword ptr [rax+zmm1*4]
+        let access = accesses(&[0x62, 0xf2, 0x7d, 0x49, 0x90, 0x04, 0x88])[0];
+        assert_eq!(access.kind(), AccessKind::Read);
+        assert_eq!((access.elements(), access.size()), (16, 4));
+
+        // vpgatherqd ymm0{k1}, dword ptr [rax+zmm1*8]
+        let access = accesses(&[0x62, 0xf2, 0x7d, 0x49, 0x91, 0x04, 0xc8])[0];
+        assert_eq!((access.elements(), access.size()), (8, 4));
+
+        // vpgatherdq zmm0{k1}, qword ptr [rax+ymm1*8]
+        let access = accesses(&[0x62, 0xf2, 0xfd, 0x49, 0x90, 0x04, 0xc8])[0];
+        assert_eq!((access.elements(), access.size()), (8, 8));
+
+        // vpgatherqd xmm0, dword ptr [rax+ymm1*4], xmm2
+        let access = accesses(&[0xc4, 0xe2, 0x6d, 0x91, 0x04, 0x88])[0];
+        assert_eq!((access.elements(), access.size()), (4, 4));
+
+        // vpscatterdd dword ptr [rax+zmm1*4]{k1}, zmm0
+        let access = accesses(&[0x62, 0xf2, 0x7d, 0x49, 0xa0, 0x04, 0x88])[0];
+        assert_eq!(access.kind(), AccessKind::Write);
+        assert_eq!((access.elements(), access.size()), (16, 4));
+    }
+
+    #[test]
+    fn implicit_accesses() {
+        // call qword ptr [rip]
+        let call = accesses(&[0xff, 0x15, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(call.len(), 2);
+        assert!(!call[0].is_implicit() && call[0].kind().is_read());
+        assert!(call[1].is_implicit() && call[1].kind().is_write());
+        assert_eq!(call[1].memory_operand(), 1);
+
+        // rep movsb
+        let movs = accesses(&[0xf3, 0xa4]);
+        assert_eq!(movs.len(), 2);
+        assert!(movs.iter().all(|access| access.is_implicit()));
+    }
+
+    #[test]
+    fn no_accesses() {
+        // lea rax, [rsp]
+        assert!(accesses(&[0x48, 0x8d, 0x04, 0x24]).is_empty());
+        // nop dword ptr [rax]
+        assert!(accesses(&[0x0f, 0x1f, 0x00]).is_empty());
+        // prefetcht0 [rax]
+        assert!(accesses(&[0x0f, 0x18, 0x08]).is_empty());
+        // add rax, rbx
+        assert!(accesses(&[0x48, 0x01, 0xd8]).is_empty());
+    }
+}
@@ -9,6 +9,7 @@ crate::macros::xed_enum! {
         _4I8 => XED_OPERAND_XTYPE_4I8,
         _4U8 => XED_OPERAND_XTYPE_4U8,
         B80,
+        BF16,
         F16,
         F32,
         F64,
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use xed_sys::*;
 
 crate::macros::xed_enum! {
@@ -369,6 +371,13 @@ crate::macros::xed_enum! {
     }
 }
 
+/// Formats the register using its lowercase assembly name (e.g. `rax`).
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{self:?}").to_ascii_lowercase())
+    }
+}
+
 impl Register {
     pub fn is_bndcfg(self) -> bool {
         (XED_REG_BNDCFG_FIRST..=XED_REG_BNDCFG_LAST).contains(&self.into_raw())
@@ -474,3 +483,16 @@ impl Register {
         (XED_REG_ZMM_FIRST..=XED_REG_ZMM_LAST).contains(&self.into_raw())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Register::RAX.to_string(), "rax");
+        assert_eq!(Register::XMM15.to_string(), "xmm15");
+        assert_eq!(format!("{:>4}", Register::K1), "  k1");
+        assert_eq!(Register::iter().count(), Register::count());
+    }
+}
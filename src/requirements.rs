@@ -0,0 +1,327 @@
+use super::{
+    decode, Chip, ChipFeatures, DecodeOptions, DecodedInst, Extension, IForm, IsaSet, State,
+};
+
+/// How often a single [`IsaSet`] or [`Extension`] is used within a
+/// [`RequirementsReport`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Usage<T> {
+    value: T,
+    count: usize,
+    first_address: u64,
+}
+
+impl<T: Copy> Usage<T> {
+    /// The ISA set or extension being used.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// The number of instructions that use it.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The address of the first instruction that uses it.
+    pub fn first_address(&self) -> u64 {
+        self.first_address
+    }
+}
+
+/// A single instruction within a [`RequirementsReport`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RequiredInst {
+    address: u64,
+    iform: IForm,
+    isa_set: IsaSet,
+    extension: Extension,
+}
+
+impl RequiredInst {
+    /// The address of the instruction.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The iform of the instruction.
+    pub fn iform(&self) -> IForm {
+        self.iform
+    }
+
+    /// The ISA set that the instruction belongs to.
+    pub fn isa_set(&self) -> IsaSet {
+        self.isa_set
+    }
+
+    /// The extension that the instruction belongs to.
+    pub fn extension(&self) -> Extension {
+        self.extension
+    }
+}
+
+/// A summary of the ISA sets, extensions, and chips needed to run a region of
+/// code.
+///
+/// # Example
+/// Find out which chips can run a function that uses AVX2.
+/// ```
+/// # use xed::*;
+/// let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+/// let code = [
+///     0xc5, 0xfd, 0xfe, 0xc1, // vpaddd ymm0, ymm0, ymm1
+///     0xc3,                   // ret
+/// ];
+///
+/// let report = RequirementsReport::from_bytes(0x1000, &code, state);
+///
+/// assert!(report.minimum_chips().contains(&Chip::HASWELL));
+/// assert!(!report.compatible_chips().contains(&Chip::IVYBRIDGE));
+///
+/// let blockers: Vec<_> = report.blockers(Chip::IVYBRIDGE).collect();
+/// assert_eq!(blockers.len(), 1);
+/// assert_eq!(blockers[0].address(), 0x1000);
+/// assert_eq!(blockers[0].isa_set(), IsaSet::AVX2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RequirementsReport {
+    insts: Vec<RequiredInst>,
+    isa_sets: Vec<Usage<IsaSet>>,
+    extensions: Vec<Usage<Extension>>,
+    undecodable: Vec<u64>,
+}
+
+impl RequirementsReport {
+    /// Build a report from a sequence of instructions that are laid out one
+    /// after the other starting at `address`.
+    pub fn new<'a, 'd: 'a, I>(address: u64, insts: I) -> Self
+    where
+        I: IntoIterator<Item = &'a DecodedInst<'d>>,
+    {
+        let mut report = Self::empty();
+        let mut address = address;
+
+        for inst in insts {
+            report.push(address, inst);
+            address = address.wrapping_add(inst.length() as u64);
+        }
+
+        report
+    }
+
+    /// Build a report by decoding every instruction in `bytes`, which are
+    /// located at `address`.
+    ///
+    /// Bytes that cannot be decoded are skipped one at a time and their
+    /// addresses are recorded in [`undecodable`](Self::undecodable).
+    pub fn from_bytes(address: u64, bytes: &[u8], state: State) -> Self {
+        let mut report = Self::empty();
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let inst_address = address.wrapping_add(offset as u64);
+
+            match decode(&bytes[offset..], DecodeOptions::new(state)) {
+                Ok(inst) => {
+                    report.push(inst_address, &inst);
+                    offset += inst.length();
+                }
+                Err(_) => {
+                    report.undecodable.push(inst_address);
+                    offset += 1;
+                }
+            }
+        }
+
+        report
+    }
+
+    fn empty() -> Self {
+        Self {
+            insts: Vec::new(),
+            isa_sets: Vec::new(),
+            extensions: Vec::new(),
+            undecodable: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, address: u64, inst: &DecodedInst) {
+        let inst = RequiredInst {
+            address,
+            iform: inst.iform(),
+            isa_set: inst.isa_set(),
+            extension: inst.extension(),
+        };
+
+        record(&mut self.isa_sets, inst.isa_set, address);
+        record(&mut self.extensions, inst.extension, address);
+        self.insts.push(inst);
+    }
+}
+
+fn record<T: Copy + PartialEq>(usages: &mut Vec<Usage<T>>, value: T, address: u64) {
+    match usages.iter_mut().find(|usage| usage.value == value) {
+        Some(usage) => usage.count += 1,
+        None => usages.push(Usage {
+            value,
+            count: 1,
+            first_address: address,
+        }),
+    }
+}
+
+impl RequirementsReport {
+    /// Every instruction in the region, in the order they appeared.
+    pub fn insts(&self) -> &[RequiredInst] {
+        &self.insts
+    }
+
+    /// The ISA sets used by the region, in the order they first appeared.
+    pub fn isa_sets(&self) -> &[Usage<IsaSet>] {
+        &self.isa_sets
+    }
+
+    /// The extensions used by the region, in the order they first appeared.
+    pub fn extensions(&self) -> &[Usage<Extension>] {
+        &self.extensions
+    }
+
+    /// The addresses of bytes that could not be decoded.
+    ///
+    /// This is always empty for reports built using [`new`](Self::new).
+    pub fn undecodable(&self) -> &[u64] {
+        &self.undecodable
+    }
+
+    /// The ISA sets used by the region as a [`ChipFeatures`].
+    pub fn features(&self) -> ChipFeatures {
+        self.isa_sets.iter().map(|usage| usage.value).collect()
+    }
+
+    /// Every chip that can execute all of the instructions in the region.
+    ///
+    /// The [`Chip::ALL`] and [`Chip::ALLREAL`] pseudo-chips are not included.
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    pub fn compatible_chips(&self) -> Vec<Chip> {
+        let features = self.features();
+
+        Chip::real_chips()
+            .filter(|&chip| features.is_subset_of(&ChipFeatures::from_chip(chip)))
+            .collect()
+    }
+
+    /// The oldest chips that can execute all of the instructions in the
+    /// region.
+    ///
+    /// This returns the earliest compatible chip in each product line, in the
+    /// same way as [`IsaSet::first_chip`].
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    pub fn minimum_chips(&self) -> Vec<Chip> {
+        let features = self.features();
+
+        Chip::earliest_in_each_line(|chip| features.is_subset_of(&ChipFeatures::from_chip(chip)))
+    }
+
+    /// The instructions that prevent the region from running on `chip`.
+    ///
+    /// > ## Note
+    /// > This function will transparently initialize the global data tables if
+    /// > they have not already been initialized.
+    pub fn blockers(&self, chip: Chip) -> impl Iterator<Item = &RequiredInst> {
+        self.insts
+            .iter()
+            .filter(move |inst| !inst.isa_set.is_valid_for_chip(chip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddressWidth, MachineMode};
+
+    fn state() -> State {
+        State::new(MachineMode::Long64, AddressWidth::QWord)
+    }
+
+    #[test]
+    fn usage_counts() {
+        let code = [
+            0x48, 0x01, 0xd8, // add rax, rbx
+            0xc5, 0xfd, 0xfe, 0xc1, // vpaddd ymm0, ymm0, ymm1
+            0x48, 0x01, 0xd8, // add rax, rbx
+            0x62, 0xf1, 0x7e, 0x49, 0x6f, 0xc1, // vmovdqu32 zmm0{k1}, zmm1
+            0xc3, // ret
+        ];
+
+        let report = RequirementsReport::from_bytes(0x1000, &code, state());
+
+        assert_eq!(report.insts().len(), 5);
+        assert!(report.undecodable().is_empty());
+
+        let isa_sets: Vec<_> = report
+            .isa_sets()
+            .iter()
+            .map(|usage| (usage.value(), usage.count(), usage.first_address()))
+            .collect();
+        assert_eq!(
+            isa_sets,
+            [
+                (IsaSet::I86, 3, 0x1000),
+                (IsaSet::AVX2, 1, 0x1003),
+                (IsaSet::AVX512F_512, 1, 0x100a),
+            ]
+        );
+
+        let extensions: Vec<_> = report.extensions().iter().map(|u| u.value()).collect();
+        assert_eq!(
+            extensions,
+            [Extension::BASE, Extension::AVX2, Extension::AVX512EVEX]
+        );
+    }
+
+    #[test]
+    fn chips() {
+        let code = [
+            0xc5, 0xfd, 0xfe, 0xc1, // vpaddd ymm0, ymm0, ymm1
+            0x62, 0xf1, 0x7e, 0x49, 0x6f, 0xc1, // vmovdqu32 zmm0{k1}, zmm1
+        ];
+
+        let report = RequirementsReport::from_bytes(0, &code, state());
+        let minimum = report.minimum_chips();
+        let compatible = report.compatible_chips();
+
+        assert_eq!(minimum, [Chip::SKYLAKE_SERVER, Chip::KNL]);
+        assert!(compatible.contains(&Chip::ICE_LAKE_SERVER));
+        assert!(!compatible.contains(&Chip::SKYLAKE));
+        assert!(!compatible.contains(&Chip::ALL));
+
+        let blockers: Vec<_> = report.blockers(Chip::HASWELL).collect();
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].address(), 4);
+        assert_eq!(
+            blockers[0].iform(),
+            IForm::VMOVDQU32_ZMMu32_MASKmskw_ZMMu32_AVX512
+        );
+        assert_eq!(report.blockers(Chip::IVYBRIDGE).count(), 2);
+    }
+
+    #[test]
+    fn undecodable_bytes() {
+        let code = [
+            0x0f, 0x0b, // ud2
+            0x06, // push es, which is invalid in 64-bit mode
+            0xc3, // ret
+        ];
+
+        let report = RequirementsReport::from_bytes(0, &code, state());
+
+        assert_eq!(report.insts().len(), 2);
+        assert_eq!(report.insts()[1].address(), 3);
+        assert_eq!(report.undecodable(), [2]);
+    }
+}
@@ -0,0 +1,167 @@
+use super::{
+    decode, AttributeSet, Category, DecodeOptions, DecodedInst, Error, Extension, FormatOptions,
+    IClass, IForm, IsaSet, State, Syntax,
+};
+
+/// An owned copy of the most important properties of a [`DecodedInst`].
+///
+/// Unlike [`DecodedInst`] this does not borrow the instruction bytes and, with
+/// the `serde` feature enabled, can be serialized. The original instruction
+/// can be recovered by decoding it again with [`decode`](Self::decode).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstSnapshot {
+    bytes: Vec<u8>,
+    iclass: IClass,
+    iform: IForm,
+    category: Category,
+    extension: Extension,
+    isa_set: IsaSet,
+    attributes: AttributeSet,
+    disassembly: String,
+}
+
+impl InstSnapshot {
+    /// Take a snapshot of `inst`.
+    pub fn new(inst: &DecodedInst) -> Self {
+        Self {
+            bytes: inst.bytes().to_vec(),
+            iclass: inst.iclass(),
+            iform: inst.iform(),
+            category: inst.category(),
+            extension: inst.extension(),
+            isa_set: inst.isa_set(),
+            attributes: inst.attributes(),
+            disassembly: inst.format(&FormatOptions::new(Syntax::INTEL)),
+        }
+    }
+
+    /// The bytes that make up the instruction.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The instruction class of the instruction.
+    pub fn iclass(&self) -> IClass {
+        self.iclass
+    }
+
+    /// The iform of the instruction.
+    pub fn iform(&self) -> IForm {
+        self.iform
+    }
+
+    /// The category of the instruction.
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// The extension that the instruction belongs to.
+    pub fn extension(&self) -> Extension {
+        self.extension
+    }
+
+    /// The ISA set that the instruction belongs to.
+    pub fn isa_set(&self) -> IsaSet {
+        self.isa_set
+    }
+
+    /// Every attribute of the instruction.
+    pub fn attributes(&self) -> AttributeSet {
+        self.attributes
+    }
+
+    /// The instruction formatted using Intel syntax with the default
+    /// [`FormatOptions`].
+    pub fn disassembly(&self) -> &str {
+        &self.disassembly
+    }
+
+    /// Decode the instruction again.
+    ///
+    /// `state` should be the same state that the instruction was originally
+    /// decoded with.
+    pub fn decode(&self, state: State) -> Result<DecodedInst<'_>, Error> {
+        decode(&self.bytes, DecodeOptions::new(state))
+    }
+}
+
+impl From<&DecodedInst<'_>> for InstSnapshot {
+    fn from(inst: &DecodedInst<'_>) -> Self {
+        Self::new(inst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AddressWidth, MachineMode};
+
+    fn snapshot() -> InstSnapshot {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        // lock add [rax], ebx
+        let bytes = [0xf0, 0x01, 0x18];
+        let inst = decode(&bytes, DecodeOptions::new(state)).unwrap();
+
+        InstSnapshot::new(&inst)
+    }
+
+    #[test]
+    fn properties() {
+        let snapshot = snapshot();
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+
+        assert_eq!(snapshot.bytes(), [0xf0, 0x01, 0x18]);
+        assert_eq!(snapshot.iclass(), IClass::ADD_LOCK);
+        assert_eq!(snapshot.disassembly(), "lock add dword ptr [rax], ebx");
+        assert_eq!(snapshot.decode(state).unwrap().iform(), snapshot.iform());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let snapshot = snapshot();
+        let json = serde_json::to_value(&snapshot).unwrap();
+
+        assert_eq!(json["iclass"], "ADD_LOCK");
+        assert_eq!(json["isa_set"], "I86");
+        assert!(json["attributes"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("LOCKED")));
+        assert_eq!(
+            serde_json::from_value::<InstSnapshot>(json).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_other_types() {
+        use crate::{Chip, ChipFeatures, Flag, FlagSet};
+
+        let state = State::new(MachineMode::LongCompat32, AddressWidth::DWord);
+        let json = serde_json::to_value(state).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "machine_mode": "LongCompat32",
+                "stack_address_width": "DWord",
+            })
+        );
+        let state: State = serde_json::from_value(json).unwrap();
+        assert_eq!(state.machine_mode(), MachineMode::LongCompat32);
+
+        let features = ChipFeatures::from_chip(Chip::HASWELL);
+        let json = serde_json::to_string(&features).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ChipFeatures>(&json).unwrap(),
+            features
+        );
+
+        let flags = FlagSet::from_flags(&[Flag::CF, Flag::ZF]);
+        assert_eq!(serde_json::to_string(&flags).unwrap(), r#"["zf","cf"]"#);
+
+        assert!(serde_json::from_str::<IClass>(r#""NOT_AN_ICLASS""#).is_err());
+    }
+}
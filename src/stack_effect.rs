@@ -0,0 +1,258 @@
+use super::{Attribute, DecodedInst, IClass, Operand, Register};
+
+/// How an instruction changes the stack pointer (`RSP`, `ESP`, or `SP`).
+///
+/// Returned by [`DecodedInst::stack_effect`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StackEffect {
+    /// The stack pointer is not changed.
+    Unchanged,
+
+    /// The stack pointer is changed by a fixed number of bytes. This is
+    /// negative when the stack grows (e.g. `push`) and positive when it
+    /// shrinks (e.g. `pop`).
+    Delta(i64),
+
+    /// The stack pointer is set to the value of another register plus an
+    /// offset (e.g. `leave`, which sets it to `rbp + 8`).
+    FromRegister {
+        /// The register that the stack pointer is restored from.
+        register: Register,
+
+        /// The offset added to the register.
+        offset: i64,
+    },
+
+    /// The stack pointer is changed in a way that cannot be determined from
+    /// the instruction alone (e.g. `mov rsp, rbp` or `and rsp, -16`).
+    Unknown,
+}
+
+impl StackEffect {
+    pub(crate) fn analyze(inst: &DecodedInst) -> Self {
+        let sp = stack_pointer(inst.stack_address_mode_bits());
+
+        if inst.iclass() == IClass::LEAVE {
+            // leave is `mov rsp, rbp` followed by `pop rbp`.
+            let mem = inst
+                .memory_operands()
+                .get(0)
+                .expect("leave has no memory operand");
+
+            return match mem.base_reg() {
+                Some(register) => Self::FromRegister {
+                    register,
+                    offset: mem.operand_length() as i64,
+                },
+                None => Self::Unknown,
+            };
+        }
+
+        let writes_sp = inst.inst().operands().any(|op| {
+            op.name().is_register()
+                && op.written()
+                && inst.reg(op.name()).is_some_and(is_stack_pointer)
+        });
+        if writes_sp {
+            return explicit_delta(inst, sp).map_or(Self::Unknown, Self::Delta);
+        }
+
+        let attrs = inst.attributes();
+        let (index, sign) = if attrs.contains(Attribute::STACKPUSH0) {
+            (0, -1)
+        } else if attrs.contains(Attribute::STACKPUSH1) {
+            (1, -1)
+        } else if attrs.contains(Attribute::STACKPOP0) {
+            (0, 1)
+        } else if attrs.contains(Attribute::STACKPOP1) {
+            (1, 1)
+        } else {
+            return Self::Unchanged;
+        };
+
+        let length = match inst.memory_operands().get(index) {
+            Some(mem) => mem.operand_length() as i64,
+            None => return Self::Unknown,
+        };
+
+        match inst.iclass() {
+            // enter pushes the frame pointer, then `level` more frame pointers
+            // if the nesting level is not zero, then allocates `size` bytes.
+            IClass::ENTER => {
+                let size = inst.unsigned_immediate() as i64;
+                let level = (inst.second_immediate() & 0x1F) as i64;
+                let pushes = if level == 0 { 1 } else { level + 1 };
+
+                Self::Delta(-(length * pushes + size))
+            }
+            IClass::RET_NEAR | IClass::RET_FAR => {
+                let has_imm = inst.inst().operands().any(|op| op.name() == Operand::IMM0);
+                let pop = if has_imm {
+                    inst.unsigned_immediate() as i64
+                } else {
+                    0
+                };
+
+                Self::Delta(length + pop)
+            }
+            // Outside of 64-bit mode the size of the interrupt frame depends on
+            // whether the privilege level changes.
+            IClass::IRET | IClass::IRETD if inst.machine_mode_bits() != 64 => Self::Unknown,
+            _ => Self::Delta(sign * length),
+        }
+    }
+
+    /// The change to the stack pointer, if it is known.
+    ///
+    /// This is `Some(0)` for [`Unchanged`](Self::Unchanged).
+    pub fn delta(&self) -> Option<i64> {
+        match *self {
+            Self::Unchanged => Some(0),
+            Self::Delta(delta) => Some(delta),
+            _ => None,
+        }
+    }
+}
+
+/// The change made by `add rsp, imm`, `sub rsp, imm`, or `lea rsp, [rsp+x]`.
+fn explicit_delta(inst: &DecodedInst, sp: Register) -> Option<i64> {
+    if inst.reg(Operand::REG0) != Some(sp) {
+        return None;
+    }
+
+    let has_imm = inst.inst().operands().any(|op| op.name() == Operand::IMM0);
+
+    match inst.iclass() {
+        IClass::ADD if has_imm => Some(inst.signed_immediate() as i64),
+        IClass::SUB if has_imm => Some(-(inst.signed_immediate() as i64)),
+        IClass::LEA => {
+            let mem = inst.memory_operands().get(0)?;
+
+            (mem.base_reg() == Some(sp) && mem.index_reg().is_none()).then(|| mem.displacement())
+        }
+        _ => None,
+    }
+}
+
+fn stack_pointer(bits: u32) -> Register {
+    match bits {
+        64 => Register::RSP,
+        32 => Register::ESP,
+        _ => Register::SP,
+    }
+}
+
+fn is_stack_pointer(reg: Register) -> bool {
+    matches!(
+        reg,
+        Register::RSP | Register::ESP | Register::SP | Register::SPL
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn stack_effect(mode: MachineMode, bytes: &[u8]) -> StackEffect {
+        let width = match mode {
+            MachineMode::Long64 => AddressWidth::QWord,
+            _ => AddressWidth::DWord,
+        };
+        let state = State::new(mode, width);
+
+        decode(bytes, DecodeOptions::new(state))
+            .unwrap()
+            .stack_effect()
+    }
+
+    fn long64(bytes: &[u8]) -> StackEffect {
+        stack_effect(MachineMode::Long64, bytes)
+    }
+
+    #[test]
+    fn push_and_pop() {
+        // push rax
+        assert_eq!(long64(&[0x50]), StackEffect::Delta(-8));
+        // push ax
+        assert_eq!(long64(&[0x66, 0x50]), StackEffect::Delta(-2));
+        // pop rbx
+        assert_eq!(long64(&[0x5b]), StackEffect::Delta(8));
+        // push qword ptr [rsp]
+        assert_eq!(long64(&[0xff, 0x34, 0x24]), StackEffect::Delta(-8));
+        // pushad
+        assert_eq!(
+            stack_effect(MachineMode::Legacy32, &[0x60]),
+            StackEffect::Delta(-32)
+        );
+    }
+
+    #[test]
+    fn calls_and_returns() {
+        // call rel32
+        assert_eq!(long64(&[0xe8, 0, 0, 0, 0]), StackEffect::Delta(-8));
+        // call qword ptr [rip]
+        assert_eq!(long64(&[0xff, 0x15, 0, 0, 0, 0]), StackEffect::Delta(-8));
+        // ret
+        assert_eq!(long64(&[0xc3]), StackEffect::Delta(8));
+        // ret 0x10
+        assert_eq!(long64(&[0xc2, 0x10, 0x00]), StackEffect::Delta(0x18));
+        // iretq
+        assert_eq!(long64(&[0x48, 0xcf]), StackEffect::Delta(40));
+        // iretd
+        assert_eq!(
+            stack_effect(MachineMode::Legacy32, &[0xcf]),
+            StackEffect::Unknown
+        );
+    }
+
+    #[test]
+    fn frames() {
+        // enter 0x20, 0
+        assert_eq!(long64(&[0xc8, 0x20, 0x00, 0x00]), StackEffect::Delta(-0x28));
+        // enter 0x20, 2
+        assert_eq!(long64(&[0xc8, 0x20, 0x00, 0x02]), StackEffect::Delta(-0x38));
+        // leave
+        assert_eq!(
+            long64(&[0xc9]),
+            StackEffect::FromRegister {
+                register: Register::RBP,
+                offset: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn explicit_updates() {
+        // sub rsp, 0x20
+        assert_eq!(long64(&[0x48, 0x83, 0xec, 0x20]), StackEffect::Delta(-0x20));
+        // add rsp, 0x1000
+        assert_eq!(
+            long64(&[0x48, 0x81, 0xc4, 0x00, 0x10, 0x00, 0x00]),
+            StackEffect::Delta(0x1000)
+        );
+        // lea rsp, [rsp+0x8]
+        assert_eq!(
+            long64(&[0x48, 0x8d, 0x64, 0x24, 0x08]),
+            StackEffect::Delta(8)
+        );
+        // add esp, 0x8
+        assert_eq!(
+            stack_effect(MachineMode::Legacy32, &[0x83, 0xc4, 0x08]),
+            StackEffect::Delta(8)
+        );
+
+        // mov rsp, rbp
+        assert_eq!(long64(&[0x48, 0x89, 0xec]), StackEffect::Unknown);
+        // and rsp, -16
+        assert_eq!(long64(&[0x48, 0x83, 0xe4, 0xf0]), StackEffect::Unknown);
+        // pop rsp
+        assert_eq!(long64(&[0x5c]), StackEffect::Unknown);
+        // add esp, 0x8 zero-extends into rsp
+        assert_eq!(long64(&[0x83, 0xc4, 0x08]), StackEffect::Unknown);
+
+        // add rax, rbx
+        assert_eq!(long64(&[0x48, 0x01, 0xd8]), StackEffect::Unchanged);
+        assert_eq!(long64(&[0x48, 0x01, 0xd8]).delta(), Some(0));
+    }
+}
@@ -115,3 +115,39 @@ impl fmt::Debug for State {
         dbg.finish()
     }
 }
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "State")]
+struct StateRepr {
+    machine_mode: MachineMode,
+    stack_address_width: AddressWidth,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StateRepr {
+            machine_mode: self.machine_mode(),
+            stack_address_width: self.stack_address_width(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for State {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = StateRepr::deserialize(deserializer)?;
+
+        if repr.machine_mode == MachineMode::Long64
+            && repr.stack_address_width != AddressWidth::QWord
+        {
+            return Err(serde::de::Error::custom(
+                "64-bit mode requires a 64-bit stack address width",
+            ));
+        }
+
+        Ok(Self::new(repr.machine_mode, repr.stack_address_width))
+    }
+}
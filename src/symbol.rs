@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::ffi::{c_char, c_int, c_void};
+use std::fmt::Write;
+
+use crate::{Register, Token, TokenKind};
+
+/// Resolves addresses to symbol names when disassembling.
+///
+/// See [`DecodedInst::disassemble_with`].
+///
+/// [`DecodedInst::disassemble_with`]: crate::DecodedInst::disassemble_with
+pub trait SymbolResolver {
+    /// Find the symbol containing `address`.
+    ///
+    /// Returns the symbol name along with the offset of `address` from the
+    /// start of the symbol, or `None` if the address does not belong to any
+    /// known symbol.
+    fn resolve(&self, address: u64) -> Option<(Cow<'_, str>, u64)>;
+}
+
+impl<R: SymbolResolver + ?Sized> SymbolResolver for &R {
+    fn resolve(&self, address: u64) -> Option<(Cow<'_, str>, u64)> {
+        R::resolve(self, address)
+    }
+}
+
+/// A symbol table keyed by the start address of each symbol.
+///
+/// Addresses resolve to the symbol with the greatest start address that is
+/// less than or equal to them.
+impl<S: AsRef<str>> SymbolResolver for BTreeMap<u64, S> {
+    fn resolve(&self, address: u64) -> Option<(Cow<'_, str>, u64)> {
+        let (&start, name) = self.range(..=address).next_back()?;
+
+        Some((Cow::Borrowed(name.as_ref()), address - start))
+    }
+}
+
+/// A resolver that remembers the annotation XED prints for every address that
+/// it resolves, so that the symbols can be split into exact tokens even when
+/// their names contain `>`.
+pub(crate) struct RecordingResolver<'r, R: ?Sized> {
+    inner: &'r R,
+    annotations: RefCell<Vec<String>>,
+}
+
+impl<'r, R: SymbolResolver + ?Sized> RecordingResolver<'r, R> {
+    pub(crate) fn new(inner: &'r R) -> Self {
+        Self {
+            inner,
+            annotations: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// The annotation XED printed for each resolved address, in the order
+    /// that they were resolved.
+    pub(crate) fn into_annotations(self) -> Vec<String> {
+        self.annotations.into_inner()
+    }
+}
+
+/// Join `tokens` back into text, moving each resolved symbol into the place of
+/// the address it names instead of following it as `<symbol>`.
+///
+/// The tokens must come from text formatted without the numeric address
+/// before each symbol. Branch targets become `call printf+0x10` and
+/// RIP-relative memory operands become `[rip+table]` or `table(%rip)`.
+pub(crate) fn inline_symbols(tokens: Vec<Token>) -> String {
+    let mut out: Vec<Token> = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        if token.kind() != TokenKind::Symbol {
+            out.push(token);
+            continue;
+        }
+
+        let name = token
+            .text()
+            .strip_prefix('<')
+            .and_then(|text| text.strip_suffix('>'))
+            .unwrap_or(token.text());
+        let symbol = Token::new(TokenKind::Symbol, name);
+
+        let previous = out.iter().rev().find(|t| t.kind() != TokenKind::Whitespace);
+        match previous.map(Token::kind) {
+            // The address itself was printed, so keep the annotation after it.
+            Some(TokenKind::BranchTarget(_) | TokenKind::Number(_)) => out.push(token),
+            Some(TokenKind::MemoryEnd) => match rip_relative_memory(&out) {
+                Some((start, rip)) => {
+                    remove_separator(&mut out);
+                    inline_memory_symbol(&mut out, start, rip, symbol);
+                }
+                None => out.push(token),
+            },
+            _ => {
+                remove_separator(&mut out);
+                out.push(symbol);
+            }
+        }
+    }
+
+    out.iter().map(Token::text).collect()
+}
+
+/// Remove the single space that XED prints before a symbol annotation.
+fn remove_separator(out: &mut Vec<Token>) {
+    if out
+        .last()
+        .is_some_and(|t| t.kind() == TokenKind::Whitespace)
+    {
+        let last = out.pop().unwrap();
+        if last.text().len() > 1 {
+            out.push(Token::new(TokenKind::Whitespace, &last.text()[1..]));
+        }
+    }
+}
+
+/// The positions of the start and of the RIP register within the memory
+/// operand at the end of `out`, or `None` if it is not RIP-relative.
+fn rip_relative_memory(out: &[Token]) -> Option<(usize, usize)> {
+    let start = out
+        .iter()
+        .rposition(|t| t.kind() == TokenKind::MemoryStart)?;
+    let rip = out[start..]
+        .iter()
+        .position(|t| matches!(t.kind(), TokenKind::Register(Register::RIP | Register::EIP)))?;
+
+    Some((start, start + rip))
+}
+
+/// Replace the displacement of the RIP-relative memory operand at the end of
+/// `out` with `symbol`.
+fn inline_memory_symbol(out: &mut Vec<Token>, start: usize, rip: usize, symbol: Token) {
+    if out[start].text() == "(" {
+        // AT&T: `-0x10(%rip)` becomes `symbol(%rip)`.
+        let mut disp = start;
+        if disp > 0 && matches!(out[disp - 1].kind(), TokenKind::Number(_)) {
+            disp -= 1;
+            if disp > 0 && out[disp - 1].text() == "-" {
+                disp -= 1;
+            }
+        }
+
+        out.splice(disp..start, [symbol]);
+    } else {
+        // Intel: `[rip+0x10]` becomes `[rip+symbol]`.
+        let end = out.pop().unwrap();
+        out.truncate(rip + 1);
+        out.extend([Token::new(TokenKind::Punctuation, "+"), symbol, end]);
+    }
+}
+
+impl<R: SymbolResolver + ?Sized> SymbolResolver for RecordingResolver<'_, R> {
+    fn resolve(&self, address: u64) -> Option<(Cow<'_, str>, u64)> {
+        let (name, offset) = self.inner.resolve(address)?;
+
+        let mut annotation = name.to_string();
+        if offset != 0 {
+            write!(annotation, "+0x{offset:x}").unwrap();
+        }
+        self.annotations.borrow_mut().push(annotation);
+
+        Some((name, offset))
+    }
+}
+
+/// The symbolic callback passed to `xed_format_context` and friends.
+///
+/// # Safety
+/// `context` must point to a valid `R`.
+pub(crate) unsafe extern "C" fn symbol_callback<R: SymbolResolver>(
+    address: u64,
+    symbol_buffer: *mut c_char,
+    buffer_length: u32,
+    offset: *mut u64,
+    context: *mut c_void,
+) -> c_int {
+    let resolver = &*(context as *const R);
+    let (name, symbol_offset) = match resolver.resolve(address) {
+        Some(symbol) => symbol,
+        None => return 0,
+    };
+
+    if buffer_length == 0 {
+        return 0;
+    }
+
+    // Leave room for the nul terminator. XED has no way to indicate that the
+    // buffer was too small so the name just gets truncated.
+    let mut len = name.len().min(buffer_length as usize - 1);
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    let buffer = std::slice::from_raw_parts_mut(symbol_buffer as *mut u8, len + 1);
+    buffer[..len].copy_from_slice(&name.as_bytes()[..len]);
+    buffer[len] = 0;
+
+    *offset = symbol_offset;
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State, Syntax};
+
+    #[test]
+    fn resolves_branch_targets() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let symbols = BTreeMap::from([(0x1000, "main"), (0x2000, "printf")]);
+
+        // call 0x2010
+        let call = decode(&[0xe8, 0x0b, 0x10, 0x00, 0x00], DecodeOptions::new(state)).unwrap();
+        // lea rax, [rip+0xffb]
+        let lea = decode(
+            &[0x48, 0x8d, 0x05, 0xfb, 0x0f, 0x00, 0x00],
+            DecodeOptions::new(state),
+        )
+        .unwrap();
+
+        assert_eq!(
+            call.disassemble_with(Syntax::INTEL, 0x1000, &symbols),
+            "call printf+0x10"
+        );
+        assert_eq!(
+            lea.disassemble_with(Syntax::INTEL, 0x1000, &symbols),
+            "lea rax, ptr [rip+printf+0x2]"
+        );
+        assert_eq!(
+            call.disassemble_with(Syntax::INTEL, 0x800, &symbols),
+            "call main+0x810"
+        );
+        assert_eq!(
+            call.disassemble_with(Syntax::INTEL, 0, &BTreeMap::<u64, &str>::new()),
+            "call 0x1010"
+        );
+
+        assert_eq!(
+            call.disassemble_with(Syntax::ATT, 0x1000, &symbols),
+            "callq  printf+0x10"
+        );
+        assert_eq!(
+            lea.disassemble_with(Syntax::ATT, 0x1000, &symbols),
+            "lea printf+0x2(%rip), %rax"
+        );
+    }
+
+    #[test]
+    fn symbol_names_with_angle_brackets() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let symbols = BTreeMap::from([
+            (0x2000, "std::vector<int>::push_back"),
+            (0x3000, "operator>"),
+        ]);
+
+        // call 0x2010
+        let call = decode(&[0xe8, 0x0b, 0x10, 0x00, 0x00], DecodeOptions::new(state)).unwrap();
+        // lea rax, [rip+0x1ff9]
+        let lea = decode(
+            &[0x48, 0x8d, 0x05, 0xf9, 0x1f, 0x00, 0x00],
+            DecodeOptions::new(state),
+        )
+        .unwrap();
+        // lea rax, [rip-0x7]
+        let lea_back = decode(
+            &[0x48, 0x8d, 0x05, 0xf9, 0xff, 0xff, 0xff],
+            DecodeOptions::new(state),
+        )
+        .unwrap();
+
+        assert_eq!(
+            call.disassemble_with(Syntax::INTEL, 0x1000, &symbols),
+            "call std::vector<int>::push_back+0x10"
+        );
+        assert_eq!(
+            lea.disassemble_with(Syntax::INTEL, 0x1000, &symbols),
+            "lea rax, ptr [rip+operator>]"
+        );
+        assert_eq!(
+            lea.disassemble_with(Syntax::ATT, 0x1000, &symbols),
+            "lea operator>(%rip), %rax"
+        );
+        assert_eq!(
+            lea_back.disassemble_with(Syntax::ATT, 0x2000, &symbols),
+            "lea std::vector<int>::push_back(%rip), %rax"
+        );
+    }
+}
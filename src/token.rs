@@ -0,0 +1,330 @@
+use super::{DecodedInst, FormatOptions, Register, Syntax};
+use crate::dialect::rewrite;
+
+/// The prefixes that XED may print before an instruction mnemonic.
+const PREFIXES: &[&str] = &[
+    "bnd",
+    "xacquire",
+    "xrelease",
+    "lock",
+    "rep",
+    "repne",
+    "hint-not-taken",
+    "hint-taken",
+    "notrack",
+    "addr32",
+    "addr16",
+    "data16",
+    "data32",
+];
+
+/// The kind of a [`Token`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TokenKind {
+    /// An instruction prefix such as `lock` or `rep`.
+    Prefix,
+
+    /// The instruction mnemonic.
+    Mnemonic,
+
+    /// A register.
+    Register(Register),
+
+    /// A numeric literal such as an immediate, displacement, or scale.
+    Number(u64),
+
+    /// The target address of a relative branch.
+    BranchTarget(u64),
+
+    /// The start of a memory operand (`[` in Intel syntax, `(` in AT&T
+    /// syntax).
+    MemoryStart,
+
+    /// The end of a memory operand (`]` in Intel syntax, `)` in AT&T syntax).
+    MemoryEnd,
+
+    /// A symbol name provided by a [`SymbolResolver`].
+    ///
+    /// XED's own output includes the surrounding angle brackets and offset
+    /// from the symbol. When formatting for a [`Dialect`] this is just the
+    /// symbol name and the offset is a separate [`Number`](Self::Number).
+    ///
+    /// [`Dialect`]: crate::Dialect
+    ///
+    /// [`SymbolResolver`]: crate::SymbolResolver
+    Symbol,
+
+    /// Any other word, such as `qword`, `ptr`, or `1to16`.
+    Keyword,
+
+    /// Separators and operators such as `,`, `+`, `:`, `{`, or `%`.
+    Punctuation,
+
+    /// Whitespace between other tokens.
+    Whitespace,
+}
+
+/// A piece of a disassembled instruction.
+///
+/// Concatenating the text of all tokens returned by [`DecodedInst::tokenize`]
+/// gives the same text as [`DecodedInst::format`].
+///
+/// [`DecodedInst::tokenize`]: crate::DecodedInst::tokenize
+/// [`DecodedInst::format`]: crate::DecodedInst::format
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Token {
+    kind: TokenKind,
+    text: String,
+}
+
+impl Token {
+    pub(crate) fn new(kind: TokenKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+        }
+    }
+
+    /// The kind of this token.
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    /// The text of this token.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Split the output of XED into tokens.
+///
+/// `text` must be the output of XED before any of the postprocessing done by
+/// [`FormatOptions`] has been applied. It is instead applied to each token
+/// individually.
+///
+/// `symbols` are the `name+0xoffset` annotations that XED printed for the
+/// resolved symbols, if they are known. They are used to find the end of each
+/// [`TokenKind::Symbol`] since symbol names may themselves contain `>`.
+pub(crate) fn tokenize(
+    inst: &DecodedInst,
+    text: &str,
+    options: &FormatOptions,
+    symbols: &[String],
+) -> Vec<Token> {
+    let mut tokens = split(inst, text, options.xed_syntax(), symbols);
+
+    if let Some(dialect) = options.dialect {
+        tokens = rewrite(inst, tokens, dialect, options);
+    }
+
+    if options.needs_postprocess() {
+        for token in tokens.iter_mut().filter(|t| t.kind != TokenKind::Symbol) {
+            token.text = options.postprocess(&token.text);
+        }
+    }
+
+    tokens
+}
+
+fn split(inst: &DecodedInst, text: &str, syntax: Syntax, symbols: &[String]) -> Vec<Token> {
+    let (memory_start, memory_end) = match syntax {
+        Syntax::ATT => ('(', ')'),
+        _ => ('[', ']'),
+    };
+    let has_branch_target = syntax != Syntax::XED && inst.branch_displacement_width() != 0;
+
+    let mut tokens = Vec::new();
+    let mut push = |kind: TokenKind, text: &str| tokens.push(Token::new(kind, text));
+
+    let mut rest = text;
+    let mut seen_mnemonic = false;
+    let mut in_memory = false;
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_whitespace() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+            push(TokenKind::Whitespace, &rest[..len]);
+            len
+        } else if !seen_mnemonic {
+            let len = rest
+                .find(|c: char| c.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let is_prefix = PREFIXES.contains(&word) && len != rest.len();
+
+            if is_prefix {
+                push(TokenKind::Prefix, word);
+            } else {
+                push(TokenKind::Mnemonic, word);
+                seen_mnemonic = true;
+            }
+
+            len
+        } else if c == '<' {
+            let len = symbols
+                .iter()
+                .filter(|symbol| {
+                    rest[1..].starts_with(symbol.as_str())
+                        && rest[1 + symbol.len()..].starts_with('>')
+                })
+                .map(|symbol| symbol.len() + 2)
+                .max()
+                .or_else(|| rest.find('>').map(|end| end + 1))
+                .unwrap_or(rest.len());
+            push(TokenKind::Symbol, &rest[..len]);
+            len
+        } else if c == memory_start {
+            in_memory = true;
+            push(TokenKind::MemoryStart, &rest[..1]);
+            1
+        } else if c == memory_end {
+            in_memory = false;
+            push(TokenKind::MemoryEnd, &rest[..1]);
+            1
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+
+            // XED prints x87 registers as `st(1)`, which doesn't split on word
+            // boundaries, and an implicit `st0` operand as just `st`.
+            if &rest[..len] == "st" {
+                if let Some([b'(', digit, b')']) = rest.as_bytes().get(len..len + 3) {
+                    if digit.is_ascii_digit() {
+                        len += 3;
+                    }
+                }
+            }
+
+            let word = &rest[..len];
+            let name = match word {
+                "st" => "ST(0)".to_owned(),
+                _ => word.to_ascii_uppercase(),
+            };
+
+            let number = match word.strip_prefix("0x") {
+                Some(digits) => u64::from_str_radix(digits, 16).ok(),
+                None if c.is_ascii_digit() => word.parse().ok(),
+                None => None,
+            };
+
+            let kind = match number {
+                Some(value) if has_branch_target && !in_memory => TokenKind::BranchTarget(value),
+                Some(value) => TokenKind::Number(value),
+                None => match name.parse() {
+                    Ok(reg) => TokenKind::Register(reg),
+                    Err(_) => TokenKind::Keyword,
+                },
+            };
+
+            push(kind, word);
+            len
+        } else {
+            let len = c.len_utf8();
+            push(TokenKind::Punctuation, &rest[..len]);
+            len
+        };
+
+        rest = &rest[len..];
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{decode, AddressWidth, DecodeOptions, MachineMode, State};
+
+    fn tokenize(bytes: &[u8], options: FormatOptions) -> Vec<(TokenKind, String)> {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let inst = decode(bytes, DecodeOptions::new(state)).unwrap();
+        let tokens = inst.tokenize(&options);
+
+        assert_eq!(
+            tokens.iter().map(|t| t.text()).collect::<String>(),
+            inst.format(&options)
+        );
+
+        tokens
+            .into_iter()
+            .map(|t| (t.kind(), t.text))
+            .filter(|(kind, _)| *kind != TokenKind::Whitespace)
+            .collect()
+    }
+
+    #[test]
+    fn intel_memory_operand() {
+        use TokenKind::*;
+
+        // lock cmpxchg qword ptr [rdx+0x10], rcx
+        let tokens = tokenize(
+            &[0xf0, 0x48, 0x0f, 0xb1, 0x4a, 0x10],
+            FormatOptions::new(Syntax::INTEL),
+        );
+        let expected = [
+            (Prefix, "lock"),
+            (Mnemonic, "cmpxchg"),
+            (Keyword, "qword"),
+            (Keyword, "ptr"),
+            (MemoryStart, "["),
+            (Register(crate::Register::RDX), "rdx"),
+            (Punctuation, "+"),
+            (Number(0x10), "0x10"),
+            (MemoryEnd, "]"),
+            (Punctuation, ","),
+            (Register(crate::Register::RCX), "rcx"),
+        ];
+
+        assert_eq!(tokens, expected.map(|(kind, text)| (kind, text.to_owned())));
+    }
+
+    #[test]
+    fn att_uppercase() {
+        use TokenKind::*;
+
+        // add rax, 0x10
+        let tokens = tokenize(
+            &[0x48, 0x83, 0xc0, 0x10],
+            FormatOptions::new(Syntax::ATT).uppercase(true),
+        );
+        let expected = [
+            (Mnemonic, "ADD"),
+            (Punctuation, "$"),
+            (Number(0x10), "0x10"),
+            (Punctuation, ","),
+            (Punctuation, "%"),
+            (Register(crate::Register::RAX), "RAX"),
+        ];
+
+        assert_eq!(tokens, expected.map(|(kind, text)| (kind, text.to_owned())));
+    }
+
+    #[test]
+    fn branch_target_and_symbol() {
+        let state = State::new(MachineMode::Long64, AddressWidth::QWord);
+        let symbols = BTreeMap::from([(0x2000, "printf")]);
+        let options = FormatOptions::new(Syntax::INTEL).runtime_address(0x1000);
+
+        // call 0x2010
+        let inst = decode(&[0xe8, 0x0b, 0x10, 0x00, 0x00], DecodeOptions::new(state)).unwrap();
+        let tokens = inst.tokenize_with(&options, &symbols);
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind()).collect();
+
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Mnemonic,
+                TokenKind::Whitespace,
+                TokenKind::BranchTarget(0x2010),
+                TokenKind::Whitespace,
+                TokenKind::Symbol
+            ]
+        );
+        assert_eq!(tokens[4].text(), "<printf+0x10>");
+    }
+}